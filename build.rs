@@ -0,0 +1,57 @@
+// Generates the registry of solutions that are linked into the `aoc` binary, see `src/template/registry.rs`.
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src/bin");
+
+    if env::var_os("CARGO_FEATURE_SINGLE_BINARY").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // solutions are named `<year>-<day>.rs`, e.g. `2025-01.rs`.
+    let mut solutions: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_stem()?.to_str()?.to_string();
+            let file = path.to_str()?.to_string();
+            let (year, day) = name.split_once('-')?;
+
+            let is_solution = path.extension()? == "rs"
+                && year.len() == 4
+                && day.len() == 2
+                && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit());
+
+            is_solution.then(|| (name.replace('-', "_"), file))
+        })
+        .collect();
+
+    solutions.sort();
+
+    let mut registry = String::new();
+
+    for (name, path) in &solutions {
+        writeln!(registry, "#[allow(dead_code)]").unwrap();
+        writeln!(registry, "#[path = {path:?}]").unwrap();
+        writeln!(registry, "mod solution_{name};").unwrap();
+    }
+
+    writeln!(
+        registry,
+        "\nstatic SOLUTIONS: &[advent_of_code::template::registry::Solution] = &["
+    )
+    .unwrap();
+
+    for (name, _) in &solutions {
+        writeln!(registry, "    solution_{name}::SOLUTION,").unwrap();
+    }
+
+    writeln!(registry, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
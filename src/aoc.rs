@@ -0,0 +1,16 @@
+// Runs any subset of the solutions in `src/bin` in-process, see `advent_of_code::template::registry`.
+
+#[cfg(feature = "dhat-heap")]
+compile_error!(
+    "the `aoc` binary does not support `dhat-heap`, use `cargo solve <day> --dhat` instead."
+);
+
+#[global_allocator]
+static ALLOC: advent_of_code::template::memory::CountingAllocator =
+    advent_of_code::template::memory::CountingAllocator;
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    advent_of_code::template::registry::main(SOLUTIONS);
+}
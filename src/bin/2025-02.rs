@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, year = 2025);
 
 fn is_valid_pt1(num: u64) -> bool {
     // See how many digits the number has
@@ -79,13 +79,13 @@ mod tests {
         assert!(is_valid_pt1(1221));
         assert!(!is_valid_pt1(123123));
 
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1227775554));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4174379265));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, year = 2025);
 
 fn find_largest(slice: &[u32]) -> (usize, u32) {
     let mut largest = slice[0];
@@ -64,13 +64,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3121910778619));
     }
 }
@@ -0,0 +1,63 @@
+use advent_of_code::grid::{Grid, Point};
+
+advent_of_code::solution!(4, year = 2025);
+
+fn input_to_grid(input: &str) -> Grid<bool> {
+    Grid::parse(input, |c| c == '@').unwrap()
+}
+
+fn is_accessible(point: Point, grid: &Grid<bool>) -> bool {
+    let adjacent_count = grid
+        .neighbours8(point)
+        .filter(|&neighbour| grid[neighbour])
+        .count();
+
+    adjacent_count < 4
+}
+
+fn accessible_rolls(grid: &Grid<bool>) -> Vec<Point> {
+    grid
+        .points()
+        .filter(|&point| grid[point] && is_accessible(point, grid))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid = input_to_grid(input);
+    Some(accessible_rolls(&grid).len() as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut grid = input_to_grid(input);
+
+    let mut removed = 0;
+    loop {
+        // Rolls are removed all at once, so collect them before updating the grid
+        let accessible = accessible_rolls(&grid);
+        if accessible.is_empty() { break; }
+
+        removed += accessible.len() as u64;
+        for point in accessible {
+            grid[point] = false;
+        }
+    }
+
+    Some(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(43));
+    }
+}
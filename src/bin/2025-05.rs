@@ -0,0 +1,59 @@
+use advent_of_code::interval_set::IntervalSet;
+use advent_of_code::parse::{self, ParseError, Parser};
+
+advent_of_code::solution!(5, year = 2025);
+
+fn parse_input(input: &str) -> parse::Result<(IntervalSet<u64>, Vec<u64>)> {
+    let sections = parse::sections(input);
+    let [ranges, ingredients] = sections.as_slice() else {
+        return Err(Parser::new(input).error("expected ranges and ingredients separated by a blank line"));
+    };
+
+    let fresh = ranges
+        .lines()
+        .map(|mut line| {
+            let lower = line.integer()?;
+            line.expect("-")?;
+            let upper = line.integer()?;
+            line.finish()?;
+            Ok(lower..=upper)
+        })
+        .collect::<parse::Result<Vec<_>>>()?;
+
+    Ok((fresh.into_iter().collect(), ingredients.clone().integers()?))
+}
+
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let (fresh, ingredients) = parse_input(input)?;
+
+    let count = ingredients
+        .iter()
+        .filter(|&&ingredient| fresh.contains(ingredient))
+        .count();
+
+    Ok(count as u64)
+}
+
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let (fresh, _ingredients) = parse_input(input)?;
+
+    // Overlapping ranges are merged by the set, so its length counts each ID once
+    Ok(fresh.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(14));
+    }
+}
@@ -1,4 +1,4 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, year = 2025);
 
 #[derive(Debug, Clone, Copy)]
 enum Token {
@@ -141,13 +141,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4277556));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3263827));
     }
 }
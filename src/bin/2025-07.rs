@@ -1,39 +1,26 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(7);
+use advent_of_code::grid::{Grid, Point};
+
+advent_of_code::solution!(7, year = 2025);
 
 struct State {
     start: (usize, usize),
     row_count: usize,
-    splitters: Vec<(usize, usize)>
+    manifold: Grid<char>
 }
 
 impl State {
     fn hit_splitter(&self, position: (usize, usize)) -> bool {
-        self
-            .splitters
-            .iter()
-            .find(|(s_x, s_y)| *s_x == position.0 && *s_y == position.1)
-            .is_some()
+        self.manifold.get(Point::from(position)) == Some(&'^')
     }
 }
 
 fn parse_input(input: &str) -> State {
-    let row_count = input.lines().count();
-    let mut start = (0, 0);
-    let mut splitters = Vec::new();
-
-    for (y, row) in input.lines().enumerate() {
-        for (x, character) in row.chars().enumerate() {
-            if character == 'S' {
-                start = (x, y);
-            } else if character == '^' {
-                splitters.push((x, y));
-            }
-        }
-    }
+    let manifold: Grid<char> = input.parse().unwrap();
+    let start = manifold.find(&'S').unwrap();
 
-    State { row_count, start, splitters }
+    State { row_count: manifold.height(), start: (start.x, start.y), manifold }
 }
 
 fn push_location_if_unique(positions: &mut Vec<(usize, usize)>, position: (usize, usize)) {
@@ -129,13 +116,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(40));
     }
 }
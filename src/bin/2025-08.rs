@@ -0,0 +1,73 @@
+use advent_of_code::parse;
+use advent_of_code::spatial::{minimum_spanning_tree, nearest_pairs, Point3};
+use advent_of_code::union_find::UnionFind;
+
+advent_of_code::solution!(8, year = 2025, parse = parse);
+
+pub struct Junctions {
+    points: Vec<Point3>,
+}
+
+pub fn parse(input: &str) -> Junctions {
+    let points = parse::lines(input)
+        .map(|mut line| {
+            let [x, y, z] = line.integers::<i64>()?[..] else {
+                return Err(line.error("expected 3 coordinates"));
+            };
+            Ok([x, y, z])
+        })
+        .collect::<parse::Result<_>>()
+        .unwrap_or_else(|e| panic!("{e}"));
+
+    Junctions { points }
+}
+
+fn part_one_partial(junctions: &Junctions, n_connections: usize) -> u64 {
+    // Connect the nearest N pairs, even if they are already in the same circuit
+    let mut circuits = UnionFind::new(junctions.points.len());
+
+    for (a, b, _) in nearest_pairs(&junctions.points, n_connections) {
+        circuits.union(a, b);
+    }
+
+    // Finally, make a product of the sizes of the largest circuits
+    circuits
+        .set_sizes()
+        .iter()
+        .take(3)
+        .map(|&size| size as u64)
+        .product()
+}
+
+pub fn part_one(junctions: &Junctions) -> Option<u64> {
+    Some(part_one_partial(junctions, 1000))
+}
+
+pub fn part_two(junctions: &Junctions) -> Option<u64> {
+    let Junctions { points } = junctions;
+
+    // The last connection that Kruskal makes joins everything into one circuit
+    let &(a, b, _) = minimum_spanning_tree(points).last()?;
+
+    Some((points[a][0] * points[b][0]) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one_partial(
+            &parse(&advent_of_code::template::read_file("examples", PUZZLE)),
+            10
+        );
+        assert_eq!(result, 40);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", PUZZLE)));
+        assert_eq!(result, Some(25272));
+    }
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, year = 2025);
 
 struct Point {
     x: u64,
@@ -205,13 +205,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(50));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(24));
     }
 }
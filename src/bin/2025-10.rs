@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
+use advent_of_code::parse::{self, ParseError, Parser};
 use good_lp::*;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(10, year = 2025);
 
 #[derive(Debug)]
 struct Machine {
@@ -11,40 +12,27 @@ struct Machine {
     joltages: Vec<usize>
 }
 
-fn strip_outer(token: &str) -> &str {
-    &token[1..token.len()-1]
-}
-
-fn line_to_machine(line: &str) -> Machine {
-    let mut line_iterator = line.split(" ").peekable();
-
+fn line_to_machine(mut line: Parser) -> parse::Result<Machine> {
     // The first item is the light array
-    let light_state = strip_outer(line_iterator.next().unwrap())
+    let light_state = line.bracketed('[', ']')?
+        .rest()
         .chars()
         .map(|c| c == '#')
         .collect();
 
+    // All middle items are the buttons
     let mut buttons = Vec::new();
-    let mut joltages = Vec::new();
-    while let Some(item) = line_iterator.next() {
-        if line_iterator.peek().is_some() {
-            // All middle items are the buttons
-            buttons.push(
-                strip_outer(item)
-                    .split(",")
-                    .map(|s| s.parse().unwrap())
-                    .collect()
-            )
-        } else {
-            // The last button is the joltages
-            joltages = strip_outer(item)
-                .split(",")
-                .map(|s| s.parse().unwrap())
-                .collect();
-        }
+    line.skip_spaces();
+    while line.peek() == Some('(') {
+        buttons.push(line.list('(', ')', Parser::integer)?);
+        line.skip_spaces();
     }
 
-    Machine { light_state, buttons, joltages }
+    // The last item is the joltages
+    let joltages = line.list('{', '}', Parser::integer)?;
+    line.finish()?;
+
+    Ok(Machine { light_state, buttons, joltages })
 }
 
 // A useful button changes at least one light to the desired state
@@ -159,26 +147,25 @@ fn solve_machine_part_2(machine: &mut Machine) -> u64 {
     total_presses as u64
 }
 
-fn parse_input(input: &str) -> Vec<Machine> {
-    input
-        .lines()
+fn parse_input(input: &str) -> parse::Result<Vec<Machine>> {
+    parse::lines(input)
         .map(line_to_machine)
         .collect()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let input = parse_input(input);
+pub fn part_one(input: &str) -> Result<u64, ParseError> {
+    let input = parse_input(input)?;
 
     let result = input
         .iter()
         .map(|m| solve_machine_part_1(m))
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut input = parse_input(input);
+pub fn part_two(input: &str) -> Result<u64, ParseError> {
+    let mut input = parse_input(input)?;
 
     let result = input
         .iter_mut()
@@ -188,7 +175,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         })
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -197,13 +184,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(33));
     }
 }
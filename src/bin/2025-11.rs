@@ -0,0 +1,43 @@
+use advent_of_code::graph::Graph;
+
+advent_of_code::solution!(11, year = 2025);
+
+// Count the paths between two devices that pass through all of the waypoints
+fn count_paths(input: &str, from: &str, to: &str, waypoints: &[&str]) -> Result<u64, String> {
+    let graph = Graph::from_adjacency_list(input).map_err(|e| e.to_string())?;
+    let device = |name: &str| graph.index(name).ok_or(format!("no device named {name}"));
+
+    let waypoints = waypoints
+        .iter()
+        .map(|name| device(name))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    graph
+        .count_paths_through(device(from)?, device(to)?, &waypoints)
+        .map_err(|e| e.to_string())
+}
+
+pub fn part_one(input: &str) -> Result<u64, String> {
+    count_paths(input, "you", "out", &[])
+}
+
+pub fn part_two(input: &str) -> Result<u64, String> {
+    count_paths(input, "svr", "out", &["dac", "fft"])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_example(PUZZLE, 1));
+        assert_eq!(result, Ok(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_example(PUZZLE, 2));
+        assert_eq!(result, Ok(2));
+    }
+}
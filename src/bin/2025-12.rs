@@ -1,10 +1,9 @@
-advent_of_code::solution!(12);
+use advent_of_code::grid::{Grid, Point};
 
-// All pieces must be 3x3 in format:
-// 0 1 2
-// 3 4 5
-// 6 7 8
-type Piece = [bool; 9];
+advent_of_code::solution!(12, year = 2025);
+
+// All pieces are 3x3 grids
+type Piece = Grid<bool>;
 
 struct Placement {
     x: usize,
@@ -13,63 +12,36 @@ struct Placement {
 }
 
 fn orientations(piece: &Piece) -> [Piece; 4] {
-    [
-        [
-            piece[0], piece[1], piece[2],
-            piece[3], piece[4], piece[5],
-            piece[6], piece[7], piece[8],
-        ],
-        [
-            piece[6], piece[3], piece[0],
-            piece[7], piece[4], piece[1],
-            piece[8], piece[5], piece[2],
-        ],
-        [
-            piece[8], piece[7], piece[6],
-            piece[5], piece[4], piece[3],
-            piece[2], piece[1], piece[0],
-        ],
-        [
-            piece[2], piece[5], piece[8],
-            piece[1], piece[4], piece[7],
-            piece[0], piece[3], piece[6],
-        ]
-    ]
+    let quarter = piece.rotate_clockwise();
+    let half = quarter.rotate_clockwise();
+    let three_quarters = half.rotate_clockwise();
+
+    [piece.clone(), quarter, half, three_quarters]
+}
+
+fn fits(board_state: &Grid<bool>, piece: &Piece, x: usize, y: usize) -> bool {
+    piece
+        .find_all(&true)
+        .all(|offset| !board_state[Point::new(x + offset.x, y + offset.y)])
 }
 
-fn valid_placements(board_state: &Vec<bool>, width: usize, height: usize, piece: &Piece) -> Vec<Placement> {
+fn valid_placements(board_state: &Grid<bool>, piece: &Piece) -> Vec<Placement> {
     let mut placements = Vec::new();
 
     for (orientation_index, oriented_piece) in orientations(piece).iter().enumerate() {
-        if board_state.iter().all(|p| !p) {
+        if board_state.find(&true).is_none() {
             // Placement of the first one will be upper left
             placements.push(Placement{ x: 0, y: 0, orientation_index });
         } else {
-            for row_i in 0..(height - 2) {
-                for col_i in 0..(width - 2) {
-                    if board_state[width * row_i + col_i] { continue; }
-
-                    // Otherwise we are in the first place we might fit this; check if it fits
-                    let mut collision = false;
-                    for piece_index in 0..9 {
-                        if !oriented_piece[piece_index] { continue; }
-
-                        let x_offset = piece_index % 3;
-                        let y_offset = piece_index / 3;
-
-                        if board_state[(width * (row_i + y_offset)) + col_i + x_offset] {
-                            collision = true;
-                            break;
-                        }
-                    }
-
-                    // If there was no collision, it is valid
-                    if !collision {
-                        placements.push(Placement { x: col_i, y: row_i, orientation_index });
-                    }
-
-                    // Either way, break to the next line
-                    break;
+            for row_i in 0..(board_state.height() - 2) {
+                // Find the first place in the row we might fit this; check if it fits
+                let Some(col_i) = (0..(board_state.width() - 2)).find(|&col_i| !board_state[Point::new(col_i, row_i)]) else {
+                    continue;
+                };
+
+                // If there was no collision, it is valid. Either way, move on to the next line
+                if fits(board_state, oriented_piece, col_i, row_i) {
+                    placements.push(Placement { x: col_i, y: row_i, orientation_index });
                 }
             }
         }
@@ -81,13 +53,11 @@ fn valid_placements(board_state: &Vec<bool>, width: usize, height: usize, piece:
 
 #[derive(Debug)]
 struct Puzzle {
-    width: usize,
-    height: usize,
     piece_counts: Vec<usize>,
-    board_state: Vec<bool>
+    board_state: Grid<bool>
 }
 
-fn recurse_puzzle(board_state: &Vec<bool>, piece_counts: &Vec<usize>, pieces: &[Piece], width: usize, height: usize) -> bool {
+fn recurse_puzzle(board_state: &Grid<bool>, piece_counts: &Vec<usize>, pieces: &[Piece]) -> bool {
     // Iterate through all available piece types
     let available_piece_indeces = piece_counts
         .iter()
@@ -97,7 +67,7 @@ fn recurse_puzzle(board_state: &Vec<bool>, piece_counts: &Vec<usize>, pieces: &[
     for piece_index in available_piece_indeces {
         let piece = &pieces[piece_index];
 
-        for valid_placement in valid_placements(&board_state, width, height, piece) {
+        for valid_placement in valid_placements(&board_state, piece) {
             // We are going to move on with a new board state. Clone the current board state and counts
             let mut new_counts = piece_counts.clone();
 
@@ -111,18 +81,14 @@ fn recurse_puzzle(board_state: &Vec<bool>, piece_counts: &Vec<usize>, pieces: &[
 
             // Apply the piece (this could probably be more efficient)
             let mut new_state = board_state.clone();
-            let oriented_piece = orientations(piece)[valid_placement.orientation_index];
-            for piece_index in 0..9 {
-                if !oriented_piece[piece_index] { continue; }
-
-                let x_offset = piece_index % 3;
-                let y_offset = piece_index / 3;
-                new_state[(valid_placement.y + y_offset) * width + valid_placement.x + x_offset] = true;
+            let oriented_piece = &orientations(piece)[valid_placement.orientation_index];
+            for offset in oriented_piece.find_all(&true) {
+                new_state[Point::new(valid_placement.x + offset.x, valid_placement.y + offset.y)] = true;
             }
 
             // Otherwise, recurse and return if a true is found
             let any_trues_in_this_path = recurse_puzzle(
-                &new_state, &new_counts, pieces, width, height
+                &new_state, &new_counts, pieces
             );
 
             if any_trues_in_this_path {
@@ -141,11 +107,11 @@ impl Puzzle {
 
         // Check: is the area large enough to just fit them all?
         let pieces_size_needed: usize = (0..pieces.len())
-            .map(|index| pieces[index].iter().filter(|&&i| i).count() * self.piece_counts[index])
+            .map(|index| pieces[index].find_all(&true).count() * self.piece_counts[index])
             .sum();
 
         // If not, don't try
-        if pieces_size_needed > (self.width * self.height) {
+        if pieces_size_needed > (self.board_state.width() * self.board_state.height()) {
             return false;
         }
 
@@ -153,26 +119,22 @@ impl Puzzle {
         return true;
 
         // If its a maybe, do the full solve (not needed)
-        // recurse_puzzle(&self.board_state, &self.piece_counts, pieces, self.width, self.height)
+        // recurse_puzzle(&self.board_state, &self.piece_counts, pieces)
     }
 }
 
-fn parse_input(input: &str) -> ([Piece; 6], Vec<Puzzle>) {
+fn parse_input(input: &str) -> (Vec<Piece>, Vec<Puzzle>) {
     let mut lines = input.lines();
-    let mut pieces: [Piece; 6] = [[false; 9]; 6];
+    let mut pieces = Vec::new();
     let mut puzzles = Vec::new();
 
     // There are always 6 pieces
-    for piece_index in 0..6 {
+    for _ in 0..6 {
         // Burn the first line
         lines.next().unwrap();
 
-        for line_index in 0..3 {
-            let line = lines.next().unwrap();
-            for (char_index, c) in line.chars().enumerate() {
-                pieces[piece_index][line_index * 3 + char_index] = c == '#';
-            }
-        }
+        let shape: Vec<&str> = lines.by_ref().take(3).collect();
+        pieces.push(Grid::parse(&shape.join("\n"), |c| c == '#').unwrap());
 
         // Burn the last line
         lines.next().unwrap();
@@ -192,9 +154,9 @@ fn parse_input(input: &str) -> ([Piece; 6], Vec<Puzzle>) {
             .collect();
 
         // Initialize board state to empty
-        let board_state = vec![false; width * height];
+        let board_state = Grid::filled(width, height, false);
 
-        puzzles.push(Puzzle { width, height, piece_counts, board_state });
+        puzzles.push(Puzzle { piece_counts, board_state });
     }
 
     (pieces, puzzles)
@@ -223,13 +185,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,368 @@
+//! Directed graphs between named nodes, e.g. devices that are connected to other devices.
+//!
+//! ```
+//! use advent_of_code::graph::Graph;
+//!
+//! let graph = Graph::from_adjacency_list("you: a b\na: out\nb: a out\n").unwrap();
+//! let [you, b, out] = ["you", "b", "out"].map(|name| graph.index(name).unwrap());
+//! assert_eq!(graph.count_paths(you, out), Ok(3));
+//! assert_eq!(graph.count_paths_through(you, out, &[b]), Ok(2));
+//! ```
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    error::Error,
+    fmt::Display,
+};
+
+use crate::parse;
+
+/// Error of algorithms that only work on acyclic graphs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// Names of the nodes on the cycle, starting and ending with the same node.
+    pub cycle: Vec<String>,
+}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "graph has a cycle: {}", self.cycle.join(" -> "))
+    }
+}
+
+impl Error for CycleError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// A directed graph with weighted edges. Nodes are interned by name and referred to by index.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+    edges: Vec<Vec<(usize, u64)>>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses lines like `name: a b c` into edges from `name` to each of the other nodes, with a weight of 1.
+    pub fn from_adjacency_list(input: &str) -> parse::Result<Self> {
+        let mut graph = Self::new();
+
+        for (name, neighbours) in parse::key_values(input)? {
+            let from = graph.node(name);
+            for neighbour in neighbours {
+                let to = graph.node(neighbour);
+                graph.add_edge(from, to, 1);
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Index of the node with a name, which is added if it does not exist yet.
+    pub fn node(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+
+        let index = self.names.len();
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), index);
+        self.edges.push(vec![]);
+        index
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn name(&self, node: usize) -> &str {
+        &self.names[node]
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Adds a directed edge. Add a second edge in the other direction for undirected graphs.
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: u64) {
+        self.edges[from].push((to, weight));
+    }
+
+    /// Outgoing edges of a node as `(neighbour, weight)`.
+    pub fn edges(&self, node: usize) -> &[(usize, u64)] {
+        &self.edges[node]
+    }
+
+    pub fn neighbours(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges[node].iter().map(|&(neighbour, _)| neighbour)
+    }
+
+    /// All nodes, ordered so that every edge points to a later node.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, CycleError> {
+        self.topological_order(0..self.len())
+    }
+
+    /// Nodes reachable from `roots` in topological order, by depth-first search.
+    fn topological_order(
+        &self,
+        roots: impl IntoIterator<Item = usize>,
+    ) -> Result<Vec<usize>, CycleError> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Visit {
+            New,
+            Active,
+            Done,
+        }
+
+        let mut visits = vec![Visit::New; self.len()];
+        let mut order = vec![];
+
+        for root in roots {
+            if visits[root] != Visit::New {
+                continue;
+            }
+
+            // Nodes on the current path, with the index of the next edge to follow.
+            let mut stack = vec![(root, 0)];
+            visits[root] = Visit::Active;
+
+            while let Some(&(node, next)) = stack.last() {
+                let Some(&(neighbour, _)) = self.edges[node].get(next) else {
+                    visits[node] = Visit::Done;
+                    order.push(node);
+                    stack.pop();
+                    continue;
+                };
+
+                if let Some(top) = stack.last_mut() {
+                    top.1 += 1;
+                }
+
+                match visits[neighbour] {
+                    Visit::New => {
+                        visits[neighbour] = Visit::Active;
+                        stack.push((neighbour, 0));
+                    }
+                    Visit::Active => {
+                        let start = stack.iter().position(|&(n, _)| n == neighbour).unwrap();
+                        let cycle = stack[start..]
+                            .iter()
+                            .map(|&(n, _)| n)
+                            .chain([neighbour])
+                            .map(|n| self.names[n].clone())
+                            .collect();
+                        return Err(CycleError { cycle });
+                    }
+                    Visit::Done => {}
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Number of paths from `from` to `to`. Fails if a cycle is reachable from `from`.
+    pub fn count_paths(&self, from: usize, to: usize) -> Result<u64, CycleError> {
+        self.count_paths_through(from, to, &[])
+    }
+
+    /// Number of paths from `from` to `to` that visit all `waypoints`, in any order.
+    /// Fails if a cycle is reachable from `from`.
+    ///
+    /// # Panics
+    /// Panics if there are more than 16 waypoints, as paths are counted per subset of visited waypoints.
+    pub fn count_paths_through(
+        &self,
+        from: usize,
+        to: usize,
+        waypoints: &[usize],
+    ) -> Result<u64, CycleError> {
+        assert!(waypoints.len() <= 16, "too many waypoints");
+
+        let visited = |node: usize| {
+            waypoints
+                .iter()
+                .enumerate()
+                .filter(|&(_, &waypoint)| waypoint == node)
+                .fold(0, |mask, (bit, _)| mask | 1 << bit)
+        };
+        let subsets = 1 << waypoints.len();
+
+        // `counts[node][mask]` is the number of paths to `node` that visited the waypoints in `mask`.
+        let mut counts = vec![vec![]; self.len()];
+        counts[from] = vec![0; subsets];
+        counts[from][visited(from)] = 1;
+
+        for node in self.topological_order([from])? {
+            let paths = std::mem::take(&mut counts[node]);
+            for neighbour in self.neighbours(node) {
+                let neighbour_visited = visited(neighbour);
+                let neighbour_counts = &mut counts[neighbour];
+                neighbour_counts.resize(subsets, 0);
+                for (mask, &count) in paths.iter().enumerate() {
+                    neighbour_counts[mask | neighbour_visited] += count;
+                }
+            }
+            if node == to {
+                return Ok(paths[subsets - 1]);
+            }
+        }
+
+        Ok(0)
+    }
+
+    /// Number of edges on the shortest path from `from` to each node, ignoring weights.
+    pub fn bfs(&self, from: usize) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = VecDeque::from([from]);
+        distances[from] = Some(0);
+
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[node].unwrap_or_default();
+            for neighbour in self.neighbours(node) {
+                if distances[neighbour].is_none() {
+                    distances[neighbour] = Some(distance + 1);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Total weight of the shortest path from `from` to each node.
+    pub fn dijkstra(&self, from: usize) -> Vec<Option<u64>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, from))]);
+
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if distances[node].is_some() {
+                continue;
+            }
+            distances[node] = Some(distance);
+
+            for &(neighbour, weight) in self.edges(node) {
+                if distances[neighbour].is_none() {
+                    queue.push(Reverse((distance + weight, neighbour)));
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{CycleError, Graph};
+
+    const DEVICES: &str = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    fn graph() -> Graph {
+        Graph::from_adjacency_list(DEVICES).unwrap()
+    }
+
+    fn nodes<const N: usize>(graph: &Graph, names: [&str; N]) -> [usize; N] {
+        names.map(|name| graph.index(name).unwrap())
+    }
+
+    #[test]
+    fn interns_nodes() {
+        let mut graph = graph();
+        assert_eq!(graph.len(), 14);
+        assert_eq!(graph.node("svr"), 0);
+        assert_eq!(graph.name(graph.index("out").unwrap()), "out");
+        assert_eq!(graph.index("zzz"), None);
+        assert!(Graph::from_adjacency_list("aaa bbb").is_err());
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = graph();
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), graph.len());
+
+        let position = |node: usize| order.iter().position(|&n| n == node).unwrap();
+        for node in 0..graph.len() {
+            for neighbour in graph.neighbours(node) {
+                assert!(position(node) < position(neighbour));
+            }
+        }
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let graph = Graph::from_adjacency_list("aaa: bbb\nbbb: ccc\nccc: ddd aaa\n").unwrap();
+        let error = graph.topological_sort().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "graph has a cycle: aaa -> bbb -> ccc -> aaa"
+        );
+
+        let [aaa, ddd] = nodes(&graph, ["aaa", "ddd"]);
+        assert!(graph.count_paths(aaa, ddd).is_err());
+        assert_eq!(graph.count_paths(ddd, aaa), Ok(0));
+    }
+
+    #[test]
+    fn counts_paths() {
+        let graph = graph();
+        let [svr, fft, dac, out, eee] = nodes(&graph, ["svr", "fft", "dac", "out", "eee"]);
+
+        assert_eq!(graph.count_paths(svr, out), Ok(8));
+        assert_eq!(graph.count_paths_through(svr, out, &[fft, dac]), Ok(2));
+        assert_eq!(graph.count_paths_through(svr, out, &[dac, fft]), Ok(2));
+        assert_eq!(graph.count_paths_through(svr, out, &[eee]), Ok(4));
+        assert_eq!(graph.count_paths_through(svr, out, &[svr]), Ok(8));
+        assert_eq!(graph.count_paths(out, svr), Ok(0));
+        assert_eq!(graph.count_paths(out, out), Ok(1));
+    }
+
+    #[test]
+    fn finds_shortest_paths() {
+        let mut graph = graph();
+        let [svr, out, ccc, hub] = nodes(&graph, ["svr", "out", "ccc", "hub"]);
+        assert_eq!(graph.bfs(svr)[out], Some(8));
+        assert_eq!(graph.bfs(out)[svr], None);
+
+        graph.add_edge(ccc, hub, 10);
+        assert_eq!(graph.bfs(svr)[hub], Some(4));
+        assert_eq!(graph.dijkstra(svr)[hub], Some(5));
+        assert_eq!(graph.dijkstra(svr)[out], Some(8));
+    }
+
+    #[test]
+    fn formats_cycle_errors() {
+        let error = CycleError {
+            cycle: vec!["a".into(), "a".into()],
+        };
+        assert_eq!(error.to_string(), "graph has a cycle: a -> a");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,365 @@
+//! Two-dimensional grids, as most puzzles come with a map of some kind.
+//!
+//! ```
+//! use advent_of_code::grid::{Grid, Point};
+//!
+//! let grid: Grid<char> = "#.\n.S".parse().unwrap();
+//! assert_eq!(grid.find(&'S'), Some(Point::new(1, 1)));
+//! assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 3);
+//! ```
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// Offsets of the 4 orthogonal neighbours of a cell: up, right, down and left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbours of a cell, including diagonals, clockwise from the top left.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// Position of a cell. `x` is the column and `y` the row, counted from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves the point by an offset, `None` if it would leave the positive quadrant.
+    pub fn offset(self, (dx, dy): (isize, isize)) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row order.
+    ///
+    /// # Panics
+    /// Panics if the number of cells does not match the size of the grid.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "expected {width}x{height} cells, got {}",
+            cells.len()
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Creates a grid with every cell set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid from lines of text, converting each character into a cell.
+    /// Fails if the lines differ in length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, String> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(format!(
+                    "line {} has {len} cells, expected {}.",
+                    height + 1,
+                    width.unwrap_or_default()
+                ));
+            }
+            cells.extend(line.chars().map(&mut cell));
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The orthogonal neighbours of a cell that lie within the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &DIRECTIONS_4)
+    }
+
+    /// The neighbours of a cell that lie within the grid, including diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &DIRECTIONS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| point.offset(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Position of the first cell that is equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Positions of all cells that are equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// The cells of a row.
+    ///
+    /// # Panics
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// The cells of a column, from top to bottom.
+    ///
+    /// # Panics
+    /// Panics if the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Creates a grid of the same size by converting each cell.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(cell).collect(),
+        )
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |p| {
+            Point::new(p.y, height - 1 - p.x)
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |p| {
+            Point::new(width - 1 - p.y, p.x)
+        })
+    }
+
+    /// Creates a grid where each cell is copied from the position `source` returns for it.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(|point| self[source(point)].clone())
+            .collect();
+        Self::new(width, height, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+/// Displays the grid row by row, for debugging. Cells are displayed without separators.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, Point};
+
+    const GRID: &str = "abc\ndef\n";
+
+    fn grid() -> Grid<char> {
+        GRID.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+
+        let bools = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+        assert_eq!(bools.find_all(&true).count(), 2);
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = grid();
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counter_clockwise(),
+            grid.clone()
+        );
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
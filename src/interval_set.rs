@@ -0,0 +1,284 @@
+//! Sets of integers stored as disjoint spans, for puzzles with large ranges of IDs or coordinates.
+//!
+//! ```
+//! use advent_of_code::interval_set::IntervalSet;
+//!
+//! let fresh: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect();
+//! assert_eq!(fresh.spans().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+//! assert!(fresh.contains(17));
+//! assert_eq!(fresh.len(), 14);
+//! ```
+use std::{fmt::Debug, ops::RangeInclusive};
+
+/// Integer types that can be stored in an [`IntervalSet`].
+pub trait Bound: Copy + Ord + Debug {
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+    /// Number of values in `start..=end`, saturating at `u64::MAX`.
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_bound {
+    ($($t:ty),*) => {
+        $(impl Bound for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u64 {
+                (end.abs_diff(start) as u64).saturating_add(1)
+            }
+        })*
+    };
+}
+
+impl_bound!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of integers, stored as sorted spans that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    spans: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { spans: vec![] }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the values of a range, merging it with the spans it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // Spans in `first..last` overlap the range or are adjacent to it.
+        let first = self
+            .spans
+            .partition_point(|&(_, e)| e.successor().is_some_and(|next| next < start));
+        let last = self
+            .spans
+            .partition_point(|&(s, _)| end.successor().is_none_or(|next| s <= next));
+
+        if first < last {
+            start = start.min(self.spans[first].0);
+            end = end.max(self.spans[last - 1].1);
+        }
+        self.spans.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes the values of a range, splitting the spans it lies within.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        *self = self.difference(&IntervalSet::from(range));
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.spans.partition_point(|&(_, end)| end < value);
+        self.spans
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// Number of values in the set, saturating at `u64::MAX`.
+    pub fn len(&self) -> u64 {
+        self.spans.iter().fold(0, |len, &(start, end)| {
+            len.saturating_add(T::count(start, end))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The disjoint spans of the set, in ascending order.
+    pub fn spans(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.spans.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.spans().chain(other.spans()).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.spans.get(i), other.spans.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                spans.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { spans }
+    }
+
+    /// The values of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut spans = vec![];
+        let mut j = 0;
+
+        for &(start, end) in &self.spans {
+            // Spans of `other` that end before this span also end before all later ones.
+            while other.spans.get(j).is_some_and(|&(_, e)| e < start) {
+                j += 1;
+            }
+
+            let mut rest = Some(start);
+            let mut k = j;
+            while let Some(rest_start) = rest
+                && let Some(&(removed_start, removed_end)) = other.spans.get(k)
+                && removed_start <= end
+            {
+                if removed_start > rest_start {
+                    spans.push((rest_start, removed_start.predecessor().unwrap()));
+                }
+                rest = removed_end.successor().filter(|&next| next <= end);
+                k += 1;
+            }
+
+            if let Some(rest_start) = rest {
+                spans.push((rest_start, end));
+            }
+        }
+
+        Self { spans }
+    }
+}
+
+impl<T: Bound> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+/// Collects ranges by sorting them once, which is faster than inserting them one by one.
+impl<T: Bound> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<(T, T)> = iter
+            .into_iter()
+            .map(RangeInclusive::into_inner)
+            .filter(|(start, end)| start <= end)
+            .collect();
+        ranges.sort_unstable();
+
+        let mut spans: Vec<(T, T)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match spans.last_mut() {
+                Some((_, last_end)) if last_end.successor().is_none_or(|next| start <= next) => {
+                    *last_end = end.max(*last_end);
+                }
+                _ => spans.push((start, end)),
+            }
+        }
+
+        Self { spans }
+    }
+}
+
+impl<T: Bound> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::BTreeSet, ops::RangeInclusive};
+
+    use super::IntervalSet;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(start, end)| start..=end).collect()
+    }
+
+    fn values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.spans().flatten().collect()
+    }
+
+    #[test]
+    fn merges_ranges() {
+        let mut inserted = IntervalSet::new();
+        let empty = RangeInclusive::new(7, 6);
+        for range in [10..=14, 3..=5, 16..=20, 12..=18, 21..=21, empty] {
+            inserted.insert(range);
+        }
+
+        let collected = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (21, 21), (7, 6)]);
+        assert_eq!(inserted, collected);
+        assert_eq!(collected.spans().collect::<Vec<_>>(), vec![3..=5, 10..=21]);
+        assert_eq!(collected.len(), 15);
+    }
+
+    #[test]
+    fn checks_membership() {
+        let set = set(&[(-5, -1), (3, 3), (8, 10)]);
+        for value in -7..13 {
+            assert_eq!(
+                set.contains(value),
+                values(&set).contains(&value),
+                "{value}"
+            );
+        }
+        assert!(IntervalSet::<i32>::new().is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 4), (8, 12), (15, 15), (20, 30)]);
+        let b = set(&[(-2, 1), (3, 9), (12, 16), (22, 23), (25, 26)]);
+        let (a_values, b_values) = (values(&a), values(&b));
+
+        let union: BTreeSet<i32> = a_values.union(&b_values).copied().collect();
+        let intersection: BTreeSet<i32> = a_values.intersection(&b_values).copied().collect();
+        let difference: BTreeSet<i32> = a_values.difference(&b_values).copied().collect();
+
+        assert_eq!(values(&a.union(&b)), union);
+        assert_eq!(values(&a.intersection(&b)), intersection);
+        assert_eq!(values(&a.difference(&b)), difference);
+        assert_eq!(
+            a.difference(&b).spans().collect::<Vec<_>>(),
+            vec![2..=2, 10..=11, 20..=21, 24..=24, 27..=30]
+        );
+    }
+
+    #[test]
+    fn handles_extreme_values() {
+        let mut set = IntervalSet::from(u64::MAX - 1..=u64::MAX);
+        set.insert(0..=u64::MAX - 2);
+        assert_eq!(set.spans().collect::<Vec<_>>(), vec![0..=u64::MAX]);
+        assert_eq!(set.len(), u64::MAX);
+
+        set.remove(0..=0);
+        set.remove(u64::MAX..=u64::MAX);
+        assert_eq!(set.spans().collect::<Vec<_>>(), vec![1..=u64::MAX - 1]);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,3 +1,9 @@
+pub mod graph;
+pub mod grid;
+pub mod interval_set;
+pub mod parse;
+pub mod spatial;
 pub mod template;
+pub mod union_find;
 
 // Use this file to add helper functions and additional modules.
@@ -1,81 +1,189 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, check_answers, download, read, scaffold, solve, time,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::PuzzleId;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold;
+    use advent_of_code::template::commands::time::TimeOptions;
+    use advent_of_code::template::{Day, InputSource, PuzzleId, Year, parse_duration, parse_size};
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
+            template: String,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
+            input: InputSource,
         },
         All {
+            year: Year,
             release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+            memory_limit: Option<u64>,
         },
         Time {
-            all: bool,
+            year: Year,
+            day: Option<Day>,
+            options: TimeOptions,
+        },
+        CheckAnswers {
+            year: Year,
             day: Option<Day>,
-            store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
+    }
+
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        year.or_else(Year::from_env)
+            .ok_or_else(|| "no year specified. Pass `--year` or set `AOC_YEAR`.".into())
+    }
+
+    /// Reads the `--year` option and the day passed as free argument.
+    fn puzzle(args: &mut pico_args::Arguments) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = year(args)?;
+        in_season(year, args.free_from_str()?)
+    }
+
+    fn in_season(year: Year, day: Day) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        PuzzleId::in_season(year, day).ok_or_else(|| {
+            format!(
+                "advent of code {year} only has {} puzzles.",
+                year.puzzle_count()
+            )
+            .into()
+        })
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw_args: Vec<String> = std::env::args().skip(1).collect();
+
+        // NOTE: `--example` takes an optional value, which `pico_args` does not support.
+        let input = if raw_args.first().is_some_and(|command| command == "solve") {
+            InputSource::take_from_args(&mut raw_args)?
+        } else {
+            InputSource::Input
+        };
+
+        let mut args =
+            pico_args::Arguments::from_vec(raw_args.into_iter().map(Into::into).collect());
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: year(&mut args)?,
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+                timeout: args.opt_value_from_fn("--timeout", parse_duration)?,
+                memory_limit: args.opt_value_from_fn("--memory-limit", parse_size)?,
             },
             Some("time") => {
-                let all = args.contains("--all");
+                let run_all = args.contains("--all");
                 let store = args.contains("--store");
+                let compare = args.contains("--compare");
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let bench_time = args.opt_value_from_fn("--bench-time", parse_duration)?;
+                let memory = args.contains("--memory");
+                let timeout = args.opt_value_from_fn("--timeout", parse_duration)?;
+                let memory_limit = args.opt_value_from_fn("--memory-limit", parse_size)?;
+                let year = year(&mut args)?;
+                let day: Option<Day> = args.opt_free_from_str()?;
+
+                if let Some(day) = day {
+                    in_season(year, day)?;
+                }
 
                 AppArguments::Time {
-                    all,
-                    day: args.opt_free_from_str()?,
-                    store,
+                    year,
+                    day,
+                    options: TimeOptions {
+                        run_all,
+                        store,
+                        // regressions are flagged if a part got slower by more than 10% by default.
+                        compare: compare.then(|| threshold.unwrap_or(10.0)),
+                        bench_time,
+                        memory,
+                        timeout,
+                        memory_limit,
+                    },
                 }
             }
+            Some("check-answers") => {
+                let year = year(&mut args)?;
+                let day: Option<Day> = args.opt_free_from_str()?;
+
+                if let Some(day) = day {
+                    in_season(year, day)?;
+                }
+
+                AppArguments::CheckAnswers { year, day }
+            }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                template: args
+                    .opt_value_from_str("--template")?
+                    .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.into()),
+                puzzle: puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit: Option<u8> = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let watch = args.contains("--watch");
+
+                if watch && (submit.is_some() || dhat) {
+                    return Err("`--watch` can not be combined with `--submit` or `--dhat`.".into());
+                }
+
+                if submit.is_some() && input != InputSource::Input {
+                    return Err("only answers for the puzzle input can be submitted.".into());
+                }
+
+                AppArguments::Solve {
+                    release,
+                    submit,
+                    dhat,
+                    watch,
+                    input,
+                    puzzle: puzzle(&mut args)?,
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                year: year(&mut args)?,
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,38 +210,51 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                jobs,
+                timeout,
+                memory_limit,
+            } => all::handle(year, release, jobs, timeout, memory_limit),
+            AppArguments::Time { year, day, options } => time::handle(year, day, &options),
+            AppArguments::CheckAnswers { year, day } => check_answers::handle(year, day),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
             AppArguments::Scaffold {
-                day,
+                puzzle,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // NOTE: download first, so the template can use the title and examples of the puzzle.
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
+                scaffold::handle(puzzle, overwrite, &template);
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch,
+                input,
+            } => solve::handle(puzzle, release, dhat, submit, watch, input),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+            AppArguments::Today { year } => {
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        download::handle(puzzle);
+                        scaffold::handle(puzzle, false, scaffold::DEFAULT_TEMPLATE);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run while advent is in season, e.g. \
+                            between the 1st and the {}th of december {year}. Please use `scaffold` \
+                            with a specific day.",
+                            year.puzzle_count()
                         );
                         process::exit(1)
                     }
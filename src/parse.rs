@@ -0,0 +1,419 @@
+//! Helpers to parse puzzle inputs that report the line and column of malformed input instead of panicking.
+//!
+//! ```
+//! use advent_of_code::parse::{self, Parser};
+//!
+//! let mut parser = Parser::new("[.##.] (3) (1,3) {3,5,4,7}");
+//! let lights = parser.bracketed('[', ']').unwrap().rest();
+//! parser.skip_spaces();
+//! let button: Vec<u32> = parser.list('(', ')', Parser::integer).unwrap();
+//! assert_eq!((lights, button), (".##.", vec![3]));
+//!
+//! assert_eq!(parse::integers::<i64>("x=-3, y=12").unwrap(), vec![-3, 12]);
+//! ```
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Error of a parser. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/* -------------------------------------------------------------------------- */
+
+/// A cursor over a part of an input. Parsers created from another parser, e.g. for a line,
+/// keep reporting positions relative to the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            end: source.len(),
+        }
+    }
+
+    /// The input that has not been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset == self.end
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        let before = &self.source[..self.offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.rest()[..len];
+        self.offset += len;
+        taken
+    }
+
+    /// A parser for the next `len` bytes, which are consumed.
+    fn split_off(&mut self, len: usize) -> Parser<'a> {
+        let parser = Parser {
+            end: self.offset + len,
+            ..*self
+        };
+        self.offset += len;
+        parser
+    }
+
+    /// Skip spaces and tabs, but not line breaks.
+    pub fn skip_spaces(&mut self) {
+        let len = self.rest().len() - self.rest().trim_start_matches([' ', '\t']).len();
+        self.advance(len);
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let len = self.rest().len() - self.rest().trim_start().len();
+        self.advance(len);
+    }
+
+    /// Consume a literal, e.g. a separator.
+    pub fn expect(&mut self, literal: &str) -> Result<()> {
+        if self.rest().starts_with(literal) {
+            self.advance(literal.len());
+            Ok(())
+        } else {
+            Err(self.error(format!(
+                "expected `{literal}`, found {}",
+                self.describe_next()
+            )))
+        }
+    }
+
+    /// Parse an integer with an optional sign.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T>
+    where
+        T::Err: Display,
+    {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with(['-', '+']));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        if digits == 0 {
+            return Err(self.error(format!(
+                "expected an integer, found {}",
+                self.describe_next()
+            )));
+        }
+
+        let start = *self;
+        let text = self.advance(sign + digits);
+        text.parse()
+            .map_err(|e| start.error(format!("invalid integer `{text}`: {e}")))
+    }
+
+    /// Parse all integers in the rest of the input, skipping anything in between.
+    /// A `-` directly before digits is read as a sign, unless it follows a digit as in the range `3-5`.
+    pub fn integers<T: FromStr>(&mut self) -> Result<Vec<T>>
+    where
+        T::Err: Display,
+    {
+        let mut integers = vec![];
+
+        while !self.is_empty() {
+            let rest = self.rest();
+            let Some(start) = rest.find(|c: char| c.is_ascii_digit()) else {
+                self.advance(rest.len());
+                break;
+            };
+
+            let is_negative = rest[..start].ends_with('-')
+                && !self.source[..self.offset + start - 1].ends_with(|c: char| c.is_ascii_digit());
+            let start = if is_negative { start - 1 } else { start };
+            self.advance(start);
+            integers.push(self.integer()?);
+        }
+
+        Ok(integers)
+    }
+
+    /// Parse a run of characters up to the next whitespace.
+    pub fn word(&mut self) -> Result<&'a str> {
+        let len = self
+            .rest()
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest().len());
+
+        if len == 0 {
+            return Err(self.error(format!("expected a word, found {}", self.describe_next())));
+        }
+
+        Ok(self.advance(len))
+    }
+
+    /// Parse everything up to a delimiter, which is consumed as well.
+    pub fn take_until(&mut self, delimiter: &str) -> Result<&'a str> {
+        let Some(len) = self.rest().find(delimiter) else {
+            return Err(self.error(format!("expected `{delimiter}`")));
+        };
+
+        let taken = self.advance(len);
+        self.advance(delimiter.len());
+        Ok(taken)
+    }
+
+    /// Parse a bracketed part of the input, e.g. `[.##.]`, and return a parser for its inside.
+    pub fn bracketed(&mut self, open: char, close: char) -> Result<Parser<'a>> {
+        self.expect(open.encode_utf8(&mut [0; 4]))?;
+
+        let Some(len) = self.rest().find(close) else {
+            return Err(self.error(format!("expected `{close}` to close `{open}`")));
+        };
+
+        let inside = self.split_off(len);
+        self.advance(close.len_utf8());
+        Ok(inside)
+    }
+
+    /// Parse a bracketed, comma-separated list, e.g. `(1,3)` or `{3, 5, 4}`.
+    pub fn list<T>(
+        &mut self,
+        open: char,
+        close: char,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut inside = self.bracketed(open, close)?;
+        let mut items = vec![];
+
+        inside.skip_spaces();
+        while !inside.is_empty() {
+            items.push(item(&mut inside)?);
+            inside.skip_spaces();
+            if !inside.is_empty() {
+                inside.expect(",")?;
+                inside.skip_spaces();
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Parse a line like `name: a b c` into its key and values.
+    pub fn key_values(&mut self) -> Result<(&'a str, Vec<&'a str>)> {
+        let mut line = self.line();
+
+        let key = line.take_until(":")?.trim();
+        if key.is_empty() {
+            return Err(line.error("expected a key before `:`"));
+        }
+
+        let values = line.rest().split_whitespace().collect();
+        Ok((key, values))
+    }
+
+    /// Consume the current line, including its line break, and return a parser for it.
+    pub fn line(&mut self) -> Parser<'a> {
+        let len = self.rest().find('\n').unwrap_or(self.rest().len());
+        let mut line = self.split_off(len);
+        self.advance(usize::from(!self.is_empty()));
+
+        if line.rest().ends_with('\r') {
+            line.end -= 1;
+        }
+        line
+    }
+
+    /// Parsers for the remaining lines. A trailing line break does not start another line.
+    pub fn lines(&self) -> impl Iterator<Item = Parser<'a>> + use<'a> {
+        let mut parser = *self;
+        std::iter::from_fn(move || (!parser.is_empty()).then(|| parser.line()))
+    }
+
+    /// Parsers for the remaining sections, which are separated by blank lines.
+    pub fn sections(&self) -> Vec<Parser<'a>> {
+        let mut sections: Vec<Parser<'a>> = vec![];
+        let mut current: Option<Parser<'a>> = None;
+
+        for line in self.lines() {
+            if line.rest().trim().is_empty() {
+                sections.extend(current.take());
+            } else if let Some(section) = &mut current {
+                section.end = line.end;
+            } else {
+                current = Some(line);
+            }
+        }
+
+        sections.extend(current);
+        sections
+    }
+
+    /// Succeeds if only whitespace is left.
+    pub fn finish(&mut self) -> Result<()> {
+        self.skip_whitespace();
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected {}", self.describe_next())))
+        }
+    }
+
+    fn describe_next(&self) -> String {
+        match self.rest().split_whitespace().next() {
+            Some(token) => format!("`{token}`"),
+            None => "end of input".into(),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parse all integers of an input, e.g. `[-3, 12]` from `x=-3, y=12`.
+pub fn integers<T: FromStr>(input: &str) -> Result<Vec<T>>
+where
+    T::Err: Display,
+{
+    Parser::new(input).integers()
+}
+
+/// Parsers for the lines of an input.
+pub fn lines(input: &str) -> impl Iterator<Item = Parser<'_>> {
+    Parser::new(input).lines()
+}
+
+/// Parsers for the sections of an input, which are separated by blank lines.
+pub fn sections(input: &str) -> Vec<Parser<'_>> {
+    Parser::new(input).sections()
+}
+
+/// Parse lines like `name: a b c` into their keys and values.
+pub fn key_values(input: &str) -> Result<Vec<(&str, Vec<&str>)>> {
+    lines(input).map(|mut line| line.key_values()).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{ParseError, Parser, integers, key_values, lines, sections};
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(integers::<i64>("1-2, x=-3\n+4"), Ok(vec![1, 2, -3, 4]));
+        assert_eq!(integers::<u8>("no numbers"), Ok(vec![]));
+
+        let error = integers::<u8>("1\n 300").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: invalid integer `300`: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "3-5\n10-14\n\n1\n5\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].rest(), "3-5\n10-14");
+        assert_eq!(sections[1].lines().count(), 2);
+
+        let error = sections[1].lines().nth(1).unwrap().error("bad");
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+
+    #[test]
+    fn parses_lists() {
+        let mut parser = Parser::new("[.##.] (1,3) {3, 5,4}");
+        assert_eq!(parser.bracketed('[', ']').unwrap().rest(), ".##.");
+        parser.skip_spaces();
+        assert_eq!(parser.list('(', ')', Parser::integer::<u8>), Ok(vec![1, 3]));
+        parser.skip_spaces();
+        assert_eq!(
+            parser.list('{', '}', Parser::integer::<u8>),
+            Ok(vec![3, 5, 4])
+        );
+        assert_eq!(parser.finish(), Ok(()));
+    }
+
+    #[test]
+    fn reports_list_errors() {
+        let error = Parser::new("(1;3)")
+            .list('(', ')', Parser::integer::<u8>)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 1,
+                column: 3,
+                message: "expected `,`, found `;3`".into()
+            }
+        );
+
+        let error = Parser::new("(1,3").list('(', ')', Parser::integer::<u8>);
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn parses_key_values() {
+        assert_eq!(
+            key_values("aaa: you hhh\nyou: out\n"),
+            Ok(vec![("aaa", vec!["you", "hhh"]), ("you", vec!["out"])])
+        );
+
+        let error = key_values("aaa: b\nccc d").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn parses_words_and_lines() {
+        let mut parser = Parser::new("12x5: 1 2\r\nnext");
+        assert_eq!(parser.integer::<u32>(), Ok(12));
+        parser.expect("x").unwrap();
+        assert_eq!(parser.take_until(":"), Ok("5"));
+        parser.skip_spaces();
+        assert_eq!(parser.word(), Ok("1"));
+        assert!(parser.expect("x").is_err());
+
+        assert_eq!(
+            lines("a\r\nb\n")
+                .map(|line| line.rest())
+                .collect::<Vec<_>>(),
+            vec!["a", "b"]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
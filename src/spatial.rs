@@ -0,0 +1,193 @@
+//! Closest pairs of points in 3D, e.g. to connect junction boxes by distance.
+//! Distances are squared Euclidean distances, which are exact for integer coordinates.
+//!
+//! ```
+//! use advent_of_code::spatial::{minimum_spanning_tree, nearest_pairs};
+//!
+//! let points = [[0, 0, 0], [10, 0, 0], [1, 1, 0]];
+//! assert_eq!(nearest_pairs(&points, 1), vec![(0, 2, 2)]);
+//! assert_eq!(minimum_spanning_tree(&points), vec![(0, 2, 2), (1, 2, 82)]);
+//! ```
+use std::collections::BinaryHeap;
+
+use crate::union_find::kruskal;
+
+pub type Point3 = [i64; 3];
+
+pub fn squared_distance(a: Point3, b: Point3) -> u64 {
+    a.iter().zip(b).map(|(a, b)| a.abs_diff(b).pow(2)).sum()
+}
+
+/// The `k` closest pairs `(a, b, squared distance)` with `a < b`, closest first.
+/// Pairs at the same distance are ordered by their indices.
+pub fn nearest_pairs(points: &[Point3], k: usize) -> Vec<(usize, usize, u64)> {
+    let tree = KdTree::new(points);
+    let mut closest = BinaryHeap::new();
+
+    if k > 0 {
+        for a in 0..points.len() {
+            tree.collect_pairs(a, 0, tree.order.len(), 0, k, &mut closest);
+        }
+    }
+
+    closest
+        .into_sorted_vec()
+        .into_iter()
+        .map(|(distance, a, b)| (a, b, distance))
+        .collect()
+}
+
+/// Minimum spanning tree of the points, as edges `(a, b, squared distance)` in the order that
+/// Kruskal's algorithm adds them. The last edge is the one that connects all points.
+pub fn minimum_spanning_tree(points: &[Point3]) -> Vec<(usize, usize, u64)> {
+    let edge_count = points.len().saturating_sub(1);
+    let pair_count = points.len() * edge_count / 2;
+    let mut k = 4 * points.len();
+
+    // Kruskal's algorithm only ever looks at the shortest pairs, so once those connect all
+    // points, the longer pairs are not needed.
+    loop {
+        let tree = kruskal(points.len(), nearest_pairs(points, k));
+        if tree.len() == edge_count || k >= pair_count {
+            return tree;
+        }
+        k *= 2;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A k-d tree stored as a permutation of the points: the median of each range splits it
+/// along the axis of its depth.
+struct KdTree<'a> {
+    points: &'a [Point3],
+    order: Vec<usize>,
+}
+
+impl<'a> KdTree<'a> {
+    fn new(points: &'a [Point3]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        Self::build(points, &mut order, 0);
+        Self { points, order }
+    }
+
+    fn build(points: &[Point3], order: &mut [usize], depth: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+
+        let axis = depth % 3;
+        let median = order.len() / 2;
+        order.select_nth_unstable_by_key(median, |&index| points[index][axis]);
+
+        let (left, right) = order.split_at_mut(median);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    /// Add the pairs of `a` with points of a greater index in `order[start..end]` to `closest`,
+    /// which keeps the `k` closest pairs as a max-heap.
+    fn collect_pairs(
+        &self,
+        a: usize,
+        start: usize,
+        end: usize,
+        depth: usize,
+        k: usize,
+        closest: &mut BinaryHeap<(u64, usize, usize)>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let median = start + (end - start) / 2;
+        let b = self.order[median];
+        if a < b {
+            let pair = (squared_distance(self.points[a], self.points[b]), a, b);
+            if closest.len() < k {
+                closest.push(pair);
+            } else if closest.peek().is_some_and(|&furthest| pair < furthest) {
+                closest.pop();
+                closest.push(pair);
+            }
+        }
+
+        let axis = depth % 3;
+        let offset = self.points[a][axis] - self.points[b][axis];
+        let (near, far) = if offset < 0 {
+            ((start, median), (median + 1, end))
+        } else {
+            ((median + 1, end), (start, median))
+        };
+
+        self.collect_pairs(a, near.0, near.1, depth + 1, k, closest);
+
+        // Points on the far side are at least as far away as the splitting plane.
+        let plane = offset.unsigned_abs().pow(2);
+        if closest.len() < k
+            || closest
+                .peek()
+                .is_some_and(|&(furthest, ..)| plane <= furthest)
+        {
+            self.collect_pairs(a, far.0, far.1, depth + 1, k, closest);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Point3, minimum_spanning_tree, nearest_pairs, squared_distance};
+    use crate::union_find::kruskal;
+
+    /// Points from a linear congruential generator, with duplicates and ties.
+    fn points(count: usize) -> Vec<Point3> {
+        let mut state: u64 = 42;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 33) as i64 % 50 - 25
+        };
+        (0..count).map(|_| [next(), next(), next()]).collect()
+    }
+
+    fn all_pairs(points: &[Point3]) -> Vec<(usize, usize, u64)> {
+        let mut pairs: Vec<(usize, usize, u64)> = (0..points.len())
+            .flat_map(|a| (a + 1..points.len()).map(move |b| (a, b)))
+            .map(|(a, b)| (a, b, squared_distance(points[a], points[b])))
+            .collect();
+        pairs.sort_unstable_by_key(|&(a, b, distance)| (distance, a, b));
+        pairs
+    }
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(squared_distance([1, -2, 3], [4, 2, 3]), 25);
+    }
+
+    #[test]
+    fn finds_nearest_pairs() {
+        let points = points(300);
+        let expected = all_pairs(&points);
+
+        for k in [0, 1, 17, 300, 5000, expected.len() + 1] {
+            let expected = &expected[..k.min(expected.len())];
+            assert_eq!(nearest_pairs(&points, k), expected, "k = {k}");
+        }
+        assert!(nearest_pairs(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn builds_minimum_spanning_trees() {
+        let mut points = points(200);
+        // An outlier forces more pairs to be considered.
+        points.push([10_000, 10_000, 10_000]);
+
+        let expected = kruskal(points.len(), all_pairs(&points));
+        assert_eq!(expected.len(), points.len() - 1);
+        assert_eq!(minimum_spanning_tree(&points), expected);
+        assert!(minimum_spanning_tree(&points[..1]).is_empty());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
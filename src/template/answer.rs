@@ -0,0 +1,203 @@
+use std::fmt::Display;
+
+use crate::template::report::PartStatus;
+
+/// Result of a solution part. Parts return any type that implements [`IntoAnswer`]:
+///  - `Option<T>` for answers that are displayed, where `None` marks a part that is not implemented yet.
+///  - `Result<T, E>` for parts that can fail, e.g. because the input has no solution.
+///  - `Answer` itself, to pick a variant explicitly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// A single-line answer, e.g. a number or a string.
+    Value(String),
+    /// A multi-line answer, e.g. letters drawn as ASCII art. The letters are read with [`ocr`] to be submitted.
+    Multiline(String),
+    /// The part is not implemented yet.
+    Unsolved,
+    /// The part failed with a message.
+    Error(String),
+}
+
+impl Answer {
+    /// Create an answer from a displayed value. Values that span several lines are multi-line answers.
+    pub fn new(value: impl Display) -> Self {
+        let value = value.to_string();
+        if value.trim_end().contains('\n') {
+            Answer::Multiline(value)
+        } else {
+            Answer::Value(value)
+        }
+    }
+
+    pub fn status(&self) -> PartStatus {
+        match self {
+            Answer::Value(_) | Answer::Multiline(_) => PartStatus::Solved,
+            Answer::Unsolved => PartStatus::Unsolved,
+            Answer::Error(_) => PartStatus::Failed,
+        }
+    }
+
+    /// The answer as it is submitted, `None` if it can not be submitted.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Value(value) => Some(value.clone()),
+            Answer::Multiline(art) => ocr(art),
+            Answer::Unsolved | Answer::Error(_) => None,
+        }
+    }
+
+    /// The answer as it is reported: the submission if there is one, otherwise the raw answer or error message.
+    pub fn text(&self) -> Option<String> {
+        match self {
+            Answer::Multiline(art) => Some(ocr(art).unwrap_or_else(|| art.clone())),
+            Answer::Error(message) => Some(message.clone()),
+            _ => self.submission(),
+        }
+    }
+}
+
+/// Conversion of the return value of a solution part into an [`Answer`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Answer {
+        self.map_or(Answer::Unsolved, Answer::new)
+    }
+}
+
+impl<T: Display, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Answer {
+        match self {
+            Ok(value) => Answer::new(value),
+            Err(e) => Answer::Error(e.to_string()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Letters of the font that puzzles use for ASCII art answers. Each letter is 4 pixels wide and 6 pixels high.
+const FONT: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read letters drawn as ASCII art, e.g. the output of a screen that puzzles ask to be read.
+/// Any character other than `.` and spaces is a lit pixel. Letters are 4 pixels wide and separated by a column.
+/// Returns `None` if the art has an unexpected size or contains letters that are not recognized.
+pub fn ocr(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().map(|c| c != '.' && c != ' ').collect())
+        .collect();
+
+    if rows.len() != 6 {
+        return None;
+    }
+
+    let width = rows.iter().map(Vec::len).max()?;
+    let pixel = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+
+    (0..width)
+        .step_by(5)
+        .map(|start| {
+            let (letter, _) = FONT.iter().find(|(_, glyph)| {
+                glyph.iter().enumerate().all(|(row, line)| {
+                    line.chars()
+                        .enumerate()
+                        .all(|(col, c)| (c == '#') == pixel(row, start + col))
+                })
+            })?;
+            Some(*letter)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answer, IntoAnswer, ocr};
+    use crate::template::report::PartStatus;
+
+    const ART: &str = "
+#..#.####.###.
+#..#.#....#..#
+####.###..#..#
+#..#.#....###.
+#..#.#....#.#.
+#..#.####.#..#
+";
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(Some(42).into_answer(), Answer::Value("42".into()));
+        assert_eq!(None::<u64>.into_answer(), Answer::Unsolved);
+        assert_eq!(
+            Ok::<_, String>("abc").into_answer(),
+            Answer::Value("abc".into())
+        );
+        assert_eq!(
+            Err::<u64, _>("no path").into_answer(),
+            Answer::Error("no path".into())
+        );
+        assert_eq!(Some(ART).into_answer(), Answer::Multiline(ART.into()));
+    }
+
+    #[test]
+    fn distinguishes_statuses() {
+        assert_eq!(Answer::Value("1".into()).status(), PartStatus::Solved);
+        assert_eq!(Answer::Multiline(ART.into()).status(), PartStatus::Solved);
+        assert_eq!(Answer::Unsolved.status(), PartStatus::Unsolved);
+        assert_eq!(Answer::Error("x".into()).status(), PartStatus::Failed);
+    }
+
+    #[test]
+    fn submits_answers() {
+        assert_eq!(Answer::Value("1".into()).submission(), Some("1".into()));
+        assert_eq!(
+            Answer::Multiline(ART.into()).submission(),
+            Some("HER".into())
+        );
+        assert_eq!(Answer::Multiline("a\nb".into()).submission(), None);
+        assert_eq!(Answer::Error("x".into()).submission(), None);
+        assert_eq!(Answer::Error("x".into()).text(), Some("x".into()));
+    }
+
+    #[test]
+    fn reads_ascii_art() {
+        assert_eq!(ocr(ART), Some("HER".into()));
+        assert_eq!(
+            ocr(&ART.replace('#', "█").replace('.', " ")),
+            Some("HER".into())
+        );
+        assert_eq!(ocr(&ART.replacen('#', ".", 1)), None);
+        assert_eq!(ocr("####"), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,337 @@
+/// Registry of accepted answers for the real puzzle inputs.
+/// Answers are recorded when a submission is accepted and checked by `cargo check-answers`.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::report::{PartReport, PartStatus};
+use crate::template::{Day, PuzzleId, Year};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the accepted answers of a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayAnswers {
+    pub year: Year,
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<DayAnswers>,
+}
+
+/// Outcome of comparing the answer of a part against the registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CheckResult {
+    /// The solution returned the accepted answer.
+    Pass,
+    /// The solution returned an answer that differs from the accepted one.
+    Mismatch { expected: String, actual: String },
+    /// The solution did not return an answer, e.g. because it crashed.
+    Fail { expected: String },
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(contents) => Answers::try_from(contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Record an accepted answer in the answers file.
+    /// An answers file that cannot be read is left untouched instead of being overwritten.
+    pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), String> {
+        let mut answers = Answers::read_from_file()?;
+        answers.insert(puzzle, part, answer);
+        answers.store_file().map_err(|e| e.to_string())
+    }
+
+    /// Returns the accepted answer of a part, if recorded.
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.puzzle() == puzzle)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if at least one answer has been recorded for the puzzle.
+    pub fn contains(&self, puzzle: PuzzleId) -> bool {
+        (1..=2).any(|part| self.get(puzzle, part).is_some())
+    }
+
+    /// Insert or overwrite the accepted answer of a part.
+    pub fn insert(&mut self, puzzle: PuzzleId, part: u8, answer: &str) {
+        let index = match self.data.iter().position(|a| a.puzzle() == puzzle) {
+            Some(index) => index,
+            None => {
+                self.data.push(DayAnswers {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.len() - 1
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = Some(answer.into()),
+            2 => self.data[index].part_2 = Some(answer.into()),
+            _ => {}
+        }
+
+        self.data.sort_unstable_by_key(DayAnswers::puzzle);
+    }
+
+    /// Compare the reports of a solution run against the recorded answers.
+    /// Parts without a recorded answer are skipped.
+    pub fn check(&self, puzzle: PuzzleId, reports: &[PartReport]) -> Vec<(u8, CheckResult)> {
+        (1..=2)
+            .filter_map(|part| {
+                let expected = self.get(puzzle, part)?.to_string();
+
+                let actual = reports
+                    .iter()
+                    .find(|r| r.part == part && r.status == PartStatus::Solved)
+                    .and_then(|r| r.answer.clone());
+
+                let result = match actual {
+                    Some(actual) if actual == expected => CheckResult::Pass,
+                    Some(actual) => CheckResult::Mismatch { expected, actual },
+                    None => CheckResult::Fail { expected },
+                };
+
+                Some((part, result))
+            })
+            .collect()
+    }
+}
+
+impl DayAnswers {
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(DayAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&DayAnswers> for JsonValue {
+    fn from(value: &DayAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, answer) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match answer {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for DayAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected answer.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(DayAnswers {
+            year,
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, CheckResult};
+    use crate::puzzle;
+    use crate::template::report::{PartReport, PartStatus};
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: Stats::single(Duration::from_micros(1)),
+            memory: None,
+        }
+    }
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.insert(puzzle!(2025, 4), 1, "1000");
+        answers.insert(puzzle!(2025, 1), 2, "42");
+        answers.insert(puzzle!(2025, 1), 1, "7");
+        answers
+    }
+
+    #[test]
+    fn inserts_answers() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].puzzle(), puzzle!(2025, 1));
+        assert_eq!(answers.get(puzzle!(2025, 1), 1), Some("7"));
+        assert_eq!(answers.get(puzzle!(2025, 1), 2), Some("42"));
+        assert_eq!(answers.get(puzzle!(2025, 4), 2), None);
+        assert!(answers.contains(puzzle!(2025, 4)));
+        assert!(!answers.contains(puzzle!(2024, 4)));
+    }
+
+    #[test]
+    fn overwrites_answers() {
+        let mut answers = get_mock_answers();
+        answers.insert(puzzle!(2025, 1), 1, "8");
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.get(puzzle!(2025, 1), 1), Some("8"));
+    }
+
+    #[test]
+    fn checks_reports() {
+        let answers = get_mock_answers();
+
+        assert_eq!(
+            answers.check(
+                puzzle!(2025, 1),
+                &[report(1, Some("7")), report(2, Some("43"))]
+            ),
+            vec![
+                (1, CheckResult::Pass),
+                (
+                    2,
+                    CheckResult::Mismatch {
+                        expected: "42".into(),
+                        actual: "43".into()
+                    }
+                )
+            ]
+        );
+
+        assert_eq!(
+            answers.check(puzzle!(2025, 4), &[report(1, None), report(2, Some("5"))]),
+            vec![(
+                1,
+                CheckResult::Fail {
+                    expected: "1000".into()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn serializes_answers() {
+        let answers = get_mock_answers();
+        let json = JsonValue::from(answers.clone()).stringify().unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_json() {
+        let _ = Answers::try_from(r#"{ "data": [{ "year": "2025" }] }"#.to_string()).unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,629 @@
+/// Client for the Advent of Code website.
+/// The session cookie is read from `AOC_SESSION` or from an `adventofcode.session` file, the same files `aoc-cli` uses.
+/// Requests go to `AOC_BASE_URL`, which defaults to `https://adventofcode.com` and can point to a local server for testing.
+/// Set `AOC_CONTACT` to a way to reach you, e.g. your repository or email, so it is sent along in the user agent.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::PuzzleId;
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE_NAME: &str = "adventofcode.session";
+const PACKAGE: &str = concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    Request(String),
+    BadStatus(u16, String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set `{SESSION_ENV}` or store it in `~/.{SESSION_FILE_NAME}`."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status, body) if body.is_empty() => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::BadStatus(status, body) => {
+                write!(f, "server responded with status {status}: {body}")
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus(
+                status,
+                response.into_string().unwrap_or_default().trim().into(),
+            ),
+            ureq::Error::Transport(e) => AocClientError::Request(e.to_string()),
+        }
+    }
+}
+
+/// Hint given by the server for wrong answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Outcome of a submission, derived from the message on the response page.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub outcome: SubmissionOutcome,
+    /// The message of the response page as plain text.
+    pub message: String,
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').into(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(TIMEOUT)
+                .user_agent(&user_agent(env::var(CONTACT_ENV).ok().as_deref()))
+                .build(),
+        }
+    }
+
+    /// Create a client from the session and base url configured in the environment.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(AocClient::new(&base_url, &session))
+    }
+
+    /// Fetch the personal puzzle input.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.day_url(puzzle));
+        Ok(self.request("GET", &url).call()?.into_string()?)
+    }
+
+    /// Fetch the puzzle description as markdown. Contains part two once part one is solved.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self
+            .request("GET", &self.day_url(puzzle))
+            .call()?
+            .into_string()?;
+
+        Ok(extract_articles(&html)
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n\n"))
+    }
+
+    /// Submit an answer for a part.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let url = format!("{}/answer", self.day_url(puzzle));
+        let html = self
+            .request("POST", &url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let message = extract_articles(&html)
+            .first()
+            .map_or_else(String::new, |article| html_to_markdown(article));
+
+        Ok(Submission {
+            outcome: parse_outcome(&message),
+            message,
+        })
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+impl Submission {
+    /// Time to wait before the next answer can be submitted, if the server asked for it.
+    pub fn cooldown(&self) -> Option<Duration> {
+        match self.outcome {
+            SubmissionOutcome::RateLimited { wait } => wait,
+            _ => parse_cooldown(&self.message),
+        }
+    }
+}
+
+/// User agent of requests, e.g. `me@example.com (advent_of_code 0.12.0)`.
+fn user_agent(contact: Option<&str>) -> String {
+    match contact.map(str::trim).filter(|contact| !contact.is_empty()) {
+        Some(contact) => format!("{contact} ({PACKAGE})"),
+        None => PACKAGE.into(),
+    }
+}
+
+/// Read the session cookie from `AOC_SESSION` or one of the session files.
+fn read_session() -> Option<String> {
+    let from_file = || {
+        get_session_paths()
+            .into_iter()
+            .find_map(|path| fs::read_to_string(path).ok())
+    };
+
+    env::var(SESSION_ENV)
+        .ok()
+        .or_else(from_file)
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+fn get_session_paths() -> Vec<PathBuf> {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+    [
+        home.map(|home| home.join(format!(".{SESSION_FILE_NAME}"))),
+        config_dir.map(|dir| dir.join(SESSION_FILE_NAME)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn parse_outcome(message: &str) -> SubmissionOutcome {
+    if message.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if message.contains("That's not the right answer") {
+        let hint = if message.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        SubmissionOutcome::Incorrect { hint }
+    } else if message.contains("You gave an answer too recently") {
+        SubmissionOutcome::RateLimited {
+            wait: parse_wait_time(message),
+        }
+    } else if message.contains("You don't seem to be solving the right level") {
+        SubmissionOutcome::WrongLevel
+    } else {
+        SubmissionOutcome::Unknown
+    }
+}
+
+/// Parse the time left to wait, e.g. `You have 1m 2s left to wait.`
+fn parse_wait_time(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|part| {
+            let value: u64 = part.trim_end_matches(['h', 'm', 's']).parse().ok()?;
+            match part.chars().last()? {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                's' => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parse the cooldown after a wrong answer, e.g. `Please wait one minute before trying again.`
+/// Repeated wrong answers escalate the cooldown mid-sentence: `..., please wait 5 minutes before trying again.`
+fn parse_cooldown(message: &str) -> Option<Duration> {
+    let message = message.to_lowercase();
+    let (_, rest) = message.split_once("please wait ")?;
+    let (amount, unit) = rest.split_once(' ')?;
+
+    let amount = match amount {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        _ => amount.parse().ok()?,
+    };
+
+    let seconds = if unit.starts_with("minute") {
+        amount * 60
+    } else if unit.starts_with("second") {
+        amount
+    } else {
+        return None;
+    };
+
+    Some(Duration::from_secs(seconds))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the contents of all `<article>` elements of a page.
+fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content_start = start + open_end + 1;
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end + "</article>".len()..];
+    }
+
+    articles
+}
+
+/// Convert the subset of HTML used in puzzle descriptions to markdown.
+/// Code blocks are kept verbatim, so examples can be extracted from the markdown file.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let Some(tag_end) = rest[tag_start..].find('>') else {
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_start + tag_end];
+        rest = &rest[tag_start + tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    // NOTE: whitespace between block elements is formatting of the html document.
+    if !in_pre && text.contains('\n') && text.trim().is_empty() {
+        return;
+    }
+
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        out.push_str(&text.replace('\n', " "));
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        AocClient, AocClientError, Hint, PACKAGE, Submission, SubmissionOutcome, html_to_markdown,
+        parse_outcome, user_agent,
+    };
+    use crate::puzzle;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    const PUZZLE_HTML: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2><p>The dial starts by pointing at <code>50</code>.</p>
+<p>For example:</p>
+<pre><code>L68
+<em>L30</em>
+R48
+</code></pre>
+<ul>
+<li>The dial is rotated <code>L68</code> &amp; points at <em>82</em>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>1000</code>.</p>
+</main></body></html>"#;
+
+    /// Serve a single response on a local port and return the raw request it received.
+    fn serve(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((key, value)) = line.split_once(':')
+                    && key.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn builds_user_agents() {
+        assert_eq!(
+            user_agent(Some("github.com/me/advent-of-code")),
+            format!("github.com/me/advent-of-code ({PACKAGE})")
+        );
+        assert_eq!(user_agent(Some(" ")), PACKAGE);
+        assert_eq!(user_agent(None), PACKAGE);
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (url, server) = serve("200 OK", "L68\nL30\n");
+        let client = AocClient::new(&url, "abc");
+
+        let input = client.input(puzzle!(2025, 1)).unwrap();
+        assert_eq!(input, "L68\nL30\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn fetches_puzzle() {
+        let (url, server) = serve("200 OK", PUZZLE_HTML);
+        let client = AocClient::new(&format!("{url}/"), "abc");
+
+        let markdown = client.puzzle(puzzle!(2025, 12)).unwrap();
+        assert!(markdown.starts_with("## --- Day 1: Secret Entrance ---"));
+        assert!(!markdown.contains("Your puzzle answer was"));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2025/day/12 HTTP/1.1"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = serve(
+            "200 OK",
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = AocClient::new(&url, "abc");
+
+        let submission = client.submit(puzzle!(2025, 1), 2, "42").unwrap();
+        assert_eq!(submission.outcome, SubmissionOutcome::Correct);
+        assert_eq!(
+            submission.message,
+            "That's the right answer! You are *one gold star* closer."
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/1/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, server) = serve(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        let client = AocClient::new(&url, "abc");
+
+        match client.input(puzzle!(2025, 1)) {
+            Err(AocClientError::BadStatus(404, body)) => assert!(body.starts_with("Please")),
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        assert_eq!(
+            html_to_markdown(super::extract_articles(PUZZLE_HTML)[0]),
+            [
+                "## --- Day 1: Secret Entrance ---",
+                "",
+                "The dial starts by pointing at `50`.",
+                "",
+                "For example:",
+                "",
+                "```",
+                "L68",
+                "L30",
+                "R48",
+                "```",
+                "",
+                "- The dial is rotated `L68` & points at *82*.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            parse_outcome(
+                "That's not the right answer; your answer is too high. Please wait one minute before trying again."
+            ),
+            SubmissionOutcome::Incorrect {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(
+            parse_outcome("That's not the right answer. If you're stuck, ..."),
+            SubmissionOutcome::Incorrect { hint: None }
+        );
+        assert_eq!(
+            parse_outcome("You gave an answer too recently. You have 1m 2s left to wait."),
+            SubmissionOutcome::RateLimited {
+                wait: Some(Duration::from_secs(62))
+            }
+        );
+        assert_eq!(
+            parse_outcome("You don't seem to be solving the right level."),
+            SubmissionOutcome::WrongLevel
+        );
+        assert_eq!(parse_outcome("?"), SubmissionOutcome::Unknown);
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        let submission = |outcome, message: &str| Submission {
+            outcome,
+            message: message.into(),
+        };
+
+        assert_eq!(
+            submission(
+                SubmissionOutcome::Incorrect { hint: None },
+                "That's not the right answer. Please wait one minute before trying again."
+            )
+            .cooldown(),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            submission(
+                SubmissionOutcome::Incorrect { hint: None },
+                "That's not the right answer. If you're stuck, make sure you're using the full input data; there are also some general tips on the about page, or you can ask for hints on the subreddit. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again. [Return to Day 1]"
+            )
+            .cooldown(),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            submission(
+                SubmissionOutcome::RateLimited {
+                    wait: Some(Duration::from_secs(3))
+                },
+                "You gave an answer too recently. You have 3s left to wait."
+            )
+            .cooldown(),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            submission(SubmissionOutcome::Correct, "That's the right answer!").cooldown(),
+            None
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
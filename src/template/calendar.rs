@@ -0,0 +1,161 @@
+/// Encapsulates the calendar of advent: how many puzzles a season has and when they unlock.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::{Day, PuzzleId, Year};
+
+/// Puzzles unlock at midnight in the timezone of the AoC server (UTC-5).
+const SERVER_UTC_OFFSET: i64 = -5;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+impl Year {
+    /// Number of puzzles in this season. Starting with 2025, advent only has 12 days.
+    pub const fn puzzle_count(self) -> u8 {
+        if self.into_inner() >= 2025 { 12 } else { 25 }
+    }
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the day is part of the year's season, returns [`None`] otherwise.
+    pub const fn in_season(year: Year, day: Day) -> Option<Self> {
+        let puzzle = Self::new(year, day);
+        if puzzle.is_in_season() {
+            Some(puzzle)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if this day is part of the year's season.
+    pub const fn is_in_season(self) -> bool {
+        self.day.into_inner() <= self.year.puzzle_count()
+    }
+
+    /// Point in time at which this puzzle unlocks, i.e. midnight UTC-5 of its day in december.
+    pub fn unlock_time(self) -> SystemTime {
+        let days = days_from_civil(i64::from(self.year.into_inner()), 12, self.day.into_inner());
+        let seconds = days * SECONDS_PER_DAY - SERVER_UTC_OFFSET * 3600;
+        // NOTE: years are validated to be >= 2015, so `seconds` is never negative.
+        UNIX_EPOCH + Duration::from_secs(seconds.unsigned_abs())
+    }
+
+    /// Returns `true` if this puzzle has unlocked at the given point in time.
+    pub fn is_unlocked(self, now: SystemTime) -> bool {
+        now >= self.unlock_time()
+    }
+
+    /// Returns the puzzle that unlocked today, or `None` outside of the season.
+    pub fn today() -> Option<Self> {
+        Self::at(SystemTime::now())
+    }
+
+    /// Returns the puzzle that unlocked on the server's calendar day of `now`, or `None` outside of the season.
+    pub fn at(now: SystemTime) -> Option<Self> {
+        let seconds = i64::try_from(now.duration_since(UNIX_EPOCH).ok()?.as_secs()).ok()?;
+        let local_seconds = seconds + SERVER_UTC_OFFSET * 3600;
+        let (year, month, day) = civil_from_days(local_seconds.div_euclid(SECONDS_PER_DAY));
+
+        if month != 12 {
+            return None;
+        }
+
+        Self::in_season(Year::new(u16::try_from(year).ok()?)?, Day::new(day)?)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Number of days since 1970-01-01 for a date in the proleptic gregorian calendar.
+/// see: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic gregorian calendar for a number of days since 1970-01-01.
+/// see: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (year, month as u8, day as u8)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{civil_from_days, days_from_civil};
+    use crate::{day, puzzle, template::PuzzleId, year};
+
+    #[test]
+    fn converts_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2025, 12, 1), 20423);
+        assert_eq!(civil_from_days(20423), (2025, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn counts_puzzles_per_season() {
+        assert_eq!(year!(2024).puzzle_count(), 25);
+        assert_eq!(year!(2025).puzzle_count(), 12);
+        assert!(puzzle!(2024, 25).is_in_season());
+        assert!(puzzle!(2025, 12).is_in_season());
+        assert!(!puzzle!(2025, 13).is_in_season());
+        assert_eq!(PuzzleId::in_season(year!(2025), day!(13)), None);
+    }
+
+    #[test]
+    fn unlocks_at_midnight_utc_minus_five() {
+        // 2025-12-01T05:00:00Z
+        let unlock = UNIX_EPOCH + Duration::from_secs(1_764_565_200);
+        assert_eq!(puzzle!(2025, 1).unlock_time(), unlock);
+        assert!(!puzzle!(2025, 1).is_unlocked(unlock - Duration::from_secs(1)));
+        assert!(puzzle!(2025, 1).is_unlocked(unlock));
+    }
+
+    #[test]
+    fn resolves_todays_puzzle() {
+        let unlock = puzzle!(2025, 3).unlock_time();
+        assert_eq!(PuzzleId::at(unlock), Some(puzzle!(2025, 3)));
+        assert_eq!(
+            PuzzleId::at(unlock - Duration::from_secs(1)),
+            Some(puzzle!(2025, 2))
+        );
+        assert_eq!(
+            PuzzleId::at(puzzle!(2025, 12).unlock_time() + Duration::from_secs(86_400)),
+            None
+        );
+        assert_eq!(
+            PuzzleId::at(puzzle!(2024, 25).unlock_time()),
+            Some(puzzle!(2024, 25))
+        );
+        assert_eq!(
+            PuzzleId::at(puzzle!(2025, 1).unlock_time() - Duration::from_secs(1)),
+            None
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
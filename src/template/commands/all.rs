@@ -1,5 +1,22 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::run_multi::{RunOptions, run_multi};
+use crate::template::{Year, all_days};
+
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    memory_limit: Option<u64>,
+) {
+    let options = RunOptions {
+        is_release,
+        jobs,
+        timeout,
+        memory_limit,
+        ..RunOptions::default()
+    };
+
+    run_multi(year, &all_days(year).collect(), &options);
 }
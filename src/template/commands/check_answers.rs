@@ -0,0 +1,73 @@
+use std::process;
+
+use crate::template::answers::{Answers, CheckResult};
+use crate::template::run_multi::{RunOptions, child_commands};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_days};
+
+/// Run every day with recorded answers in release mode and compare the results against them.
+/// Exits with a non-zero status if any part does not match.
+pub fn handle(year: Year, day: Option<Day>) {
+    let answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let puzzles: Vec<PuzzleId> = all_days(year)
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .map(|d| PuzzleId::new(year, d))
+        .filter(|puzzle| answers.contains(*puzzle))
+        .collect();
+
+    if puzzles.is_empty() {
+        println!("No answers recorded for the selected days yet.");
+        return;
+    }
+
+    let options = RunOptions {
+        is_release: true,
+        is_quiet: true,
+        ..RunOptions::default()
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+
+    for (i, puzzle) in puzzles.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
+
+        let reports = child_commands::run_solution(puzzle, &options)
+            .unwrap()
+            .map(|run| run.reports)
+            .unwrap_or_default();
+
+        for (part, result) in answers.check(puzzle, &reports) {
+            match result {
+                CheckResult::Pass => {
+                    passed += 1;
+                    println!("Part {part}: ✔ pass");
+                }
+                CheckResult::Mismatch { expected, actual } => {
+                    failed += 1;
+                    println!("Part {part}: ✖ mismatch, expected {expected}, got {actual}");
+                }
+                CheckResult::Fail { expected } => {
+                    failed += 1;
+                    println!("Part {part}: ✖ fail, expected {expected}, got no answer");
+                }
+            }
+        }
+    }
+
+    println!("\n{ANSI_BOLD}Passed:{ANSI_RESET} {passed}, {ANSI_BOLD}Failed:{ANSI_RESET} {failed}");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
@@ -1,16 +1,41 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::aoc_client::AocClient;
+use crate::template::{PuzzleId, examples};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+pub fn handle(puzzle: PuzzleId) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for folder in ["inputs", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{}/{folder}", puzzle.year)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let input_path = puzzle.data_path("inputs", "txt");
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    let result = client
+        .input(puzzle)
+        .and_then(|input| Ok(fs::write(&input_path, input)?))
+        .and_then(|()| client.puzzle(puzzle))
+        .and_then(|description| Ok(fs::write(&puzzle_path, description)?));
+
+    if let Err(e) = result {
+        eprintln!("Failed to download puzzle: {e}");
         process::exit(1);
-    };
+    }
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+
+    if let Err(e) = examples::apply(puzzle) {
+        eprintln!("Failed to extract examples: {e}");
+    }
 }
@@ -1,17 +1,31 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::PuzzleId;
+use crate::template::aoc_client::AocClient;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = match client.puzzle(puzzle) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to fetch puzzle: {e}");
+            process::exit(1);
+        }
     };
+
+    // NOTE: the description is cached, part two is only part of it once part one is solved.
+    if let Err(e) = fs::create_dir_all(format!("data/{}/puzzles", puzzle.year))
+        .and_then(|()| fs::write(puzzle.data_path("puzzles", "md"), &description))
+    {
+        eprintln!("Failed to write puzzle file: {e}");
+    }
+
+    println!("{description}");
 }
@@ -1,13 +1,15 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{PuzzleId, examples};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+/// Directory of the module templates, relative to the project root.
+const TEMPLATES_DIR: &str = "templates";
+pub const DEFAULT_TEMPLATE: &str = "default";
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +21,70 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty file, unless it exists already.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    if Path::new(path).exists() {
+        return Ok(false);
+    }
+    File::create(path)?;
+    Ok(true)
+}
+
+fn read_template(name: &str) -> Result<String, String> {
+    let path = format!("{TEMPLATES_DIR}/{name}.txt");
+
+    fs::read_to_string(&path).map_err(|e| {
+        let available = fs::read_dir(TEMPLATES_DIR)
+            .map(|entries| {
+                let mut names: Vec<String> = entries
+                    .filter_map(|entry| {
+                        let path = entry.ok()?.path();
+                        Some(path.file_stem()?.to_str()?.to_string())
+                    })
+                    .collect();
+                names.sort();
+                names.join(", ")
+            })
+            .unwrap_or_default();
+
+        format!("Failed to read template \"{path}\": {e}. Available templates: {available}.")
+    })
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Replace the placeholders of a module template:
+///  - `%DAY_NUMBER%` and `%YEAR%` identify the puzzle.
+///  - `%TITLE%` is the title from the puzzle description, if it was downloaded already.
+fn render_template(template: &str, puzzle: PuzzleId) -> String {
+    let title = fs::read_to_string(puzzle.data_path("puzzles", "md"))
+        .ok()
+        .and_then(|markdown| examples::parse_title(&markdown))
+        .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()));
+
+    template
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%TITLE%", &title)
+}
+
+pub fn handle(puzzle: PuzzleId, overwrite: bool, template: &str) {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = puzzle.bin_path();
+
+    let template = match read_template(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{}/{folder}", puzzle.year)) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +94,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(render_template(&template, puzzle).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,9 +105,10 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -65,15 +116,26 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {}
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
         }
     }
 
+    // NOTE: fill in the examples if the puzzle was downloaded before scaffolding.
+    if Path::new(&puzzle.data_path("puzzles", "md")).exists()
+        && let Err(e) = examples::apply(puzzle)
+    {
+        eprintln!("Failed to extract examples: {e}");
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
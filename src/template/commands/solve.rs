@@ -1,9 +1,21 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{InputSource, PuzzleId, watch};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    is_watch: bool,
+    input: InputSource,
+) {
+    if is_watch {
+        watch::watch(puzzle, release, input);
+        return;
+    }
+
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -17,6 +29,7 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     }
 
     cmd_args.push("--".to_string());
+    cmd_args.extend(input.to_args());
 
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
@@ -1,40 +1,154 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
+use crate::template::history::{History, HistoryEntry, get_machine_id};
+use crate::template::run_multi::{RunOptions, run_multi};
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Flags of the `time` command.
+#[derive(Debug, Clone, Default)]
+pub struct TimeOptions {
+    /// Re-run days that are already fully benched.
+    pub run_all: bool,
+    /// Store the timings and update the benchmarks in the README.
+    pub store: bool,
+    /// Compare days against the last run stored in the history on this machine.
+    /// Parts that got slower by more than this percentage are flagged and the command exits with a non-zero status.
+    pub compare: Option<f64>,
+    /// Time budget for benching a single part.
+    pub bench_time: Option<Duration>,
+    /// Record heap allocations of each part alongside its timings.
+    pub memory: bool,
+    pub timeout: Option<Duration>,
+    pub memory_limit: Option<u64>,
+}
+
+pub fn handle(year: Year, day: Option<Day>, options: &TimeOptions) {
+    let stored_timings = match Timings::read_from_file() {
+        Ok(timings) => timings,
+        Err(e) => {
+            eprintln!("Failed to read stored timings: {e}");
+            process::exit(1);
+        }
+    };
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            // comparisons need fresh timings for every day.
+            if options.run_all || options.compare.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !stored_timings.is_day_complete(PuzzleId::new(year, *day)))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let run_options = RunOptions {
+        is_release: true,
+        is_timed: true,
+        bench_time: options.bench_time,
+        is_memory: options.memory,
+        timeout: options.timeout,
+        memory_limit: options.memory_limit,
+        ..RunOptions::default()
+    };
 
-    if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+    let timings = run_multi(year, &days_to_run, &run_options).unwrap();
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
+    // NOTE: compare before storing, the stored run would be its own baseline otherwise.
+    let regressions = options
+        .compare
+        .map(|threshold| compare_timings(&timings, threshold));
+
+    if options.store {
+        store_timings(year, &timings);
+    }
+
+    if regressions.is_some_and(|count| count > 0) {
+        process::exit(1);
+    }
+}
+
+/// Print the comparison against the history and return the number of regressions.
+fn compare_timings(timings: &Timings, threshold: f64) -> usize {
+    let history = match History::read_from_file() {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let comparisons = history.compare(timings, get_machine_id().as_deref());
+
+    println!("\n{ANSI_BOLD}Comparison{ANSI_RESET} (threshold: {threshold}%)");
+
+    if comparisons.is_empty() {
+        println!("No previous runs stored on this machine. Store one with `cargo time --store`.");
+        return 0;
+    }
+
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let part = match comparison.part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let is_regression = comparison.is_regression(threshold);
+        if is_regression {
+            regressions += 1;
+        }
+
+        let change = match comparison.change_percent() {
+            Some(change) => format!("{change:+.1}%"),
+            None => "no baseline".to_string(),
+        };
+
+        println!(
+            "Day {} {part}: {:.1?} -> {:.1?} ({change}){}",
+            comparison.puzzle.day,
+            Duration::from_nanos(comparison.before as u64),
+            Duration::from_nanos(comparison.after as u64),
+            if is_regression { " ✖ regressed" } else { "" }
+        );
+    }
+
+    regressions
+}
+
+/// Merge timings into the timings file, append them to the history and update the benchmarks in the README.
+/// Stored timings that cannot be read are left untouched instead of being overwritten.
+pub(crate) fn store_timings(year: Year, timings: &Timings) {
+    let stored_timings = match Timings::read_from_file() {
+        Ok(timings) => timings,
+        Err(e) => {
+            eprintln!("Failed to read stored timings, not storing benchmarks: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = History::append(&HistoryEntry::new(timings.clone())) {
+        eprintln!("Failed to append benchmarks to history: {e}");
+    }
+
+    let merged_timings = stored_timings.merge(timings);
+    merged_timings.store_file().unwrap();
+
+    println!();
+    match readme_benchmarks::update(merged_timings.for_year(year)) {
+        Ok(()) => {
+            println!("Stored updated benchmarks.");
+        }
+        Err(_) => {
+            eprintln!("Failed to store updated benchmarks.");
         }
     }
 }
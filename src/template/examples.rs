@@ -0,0 +1,338 @@
+/// Extracts examples and their expected answers from a downloaded puzzle description.
+/// The example of a part is the first code block that is introduced as an example. Its answer is the last emphasized
+/// inline code of the part, which is how puzzle descriptions highlight the result of the example.
+use std::{fs, io};
+
+use crate::template::PuzzleId;
+
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+const EMPTY_ASSERTION: &str = "assert_eq!(result, None);";
+const EMPTY_STRING_ASSERTION: &str = "assert_eq!(result.as_deref(), None);";
+
+/// Example of a single part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    /// Input of the example, `None` if the part reuses the example of part one.
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Examples of both parts of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub part_1: Example,
+    pub part_2: Option<Example>,
+}
+
+/// Extract the examples from a puzzle description in markdown. Part two is only present once part one is solved.
+pub fn extract(markdown: &str) -> PuzzleExamples {
+    let (part_1, part_2) = match markdown.split_once(PART_TWO_HEADING) {
+        Some((part_1, part_2)) => (part_1, Some(part_2)),
+        None => (markdown, None),
+    };
+
+    let part_1 = extract_part(part_1);
+
+    let part_2 = part_2.map(|section| {
+        let mut example = extract_part(section);
+        // NOTE: part two often references the example of part one again.
+        if example.input == part_1.input {
+            example.input = None;
+        }
+        example
+    });
+
+    PuzzleExamples { part_1, part_2 }
+}
+
+fn extract_part(section: &str) -> Example {
+    let mut text = String::new();
+    let mut paragraph = String::new();
+    let mut blocks: Vec<(String, String)> = vec![];
+    let mut block: Option<String> = None;
+
+    for line in section.lines() {
+        match (&mut block, line.trim_end() == "```") {
+            (None, true) => block = Some(String::new()),
+            (Some(content), true) => {
+                blocks.push((paragraph.clone(), content.clone()));
+                block = None;
+            }
+            (Some(content), false) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            (None, false) => {
+                if !line.trim().is_empty() {
+                    paragraph = line.to_string();
+                }
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|(paragraph, _)| paragraph.to_lowercase().contains("example"))
+        .or_else(|| {
+            blocks
+                .iter()
+                .find(|(_, content)| content.lines().count() > 1)
+        })
+        .map(|(_, content)| content.clone());
+
+    Example {
+        input,
+        answer: find_emphasized_code(&text).pop(),
+    }
+}
+
+/// Parse the title from the heading of a puzzle description, e.g. `## --- Day 1: Secret Entrance ---`.
+pub fn parse_title(markdown: &str) -> Option<String> {
+    let heading = markdown
+        .lines()
+        .find(|line| line.starts_with("## --- Day"))?;
+    let title = heading
+        .trim_start_matches("## ")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace());
+    Some(title.to_string())
+}
+
+/// Find all inline code that is emphasized, e.g. `` `*42*` `` or `` *`42`* ``.
+fn find_emphasized_code(text: &str) -> Vec<String> {
+    let mut matches = vec![];
+    let mut rest = text;
+
+    while let Some(index) = rest.find(['`', '*']) {
+        rest = &rest[index..];
+
+        let close = if rest.starts_with("`*") {
+            "*`"
+        } else if rest.starts_with("*`") {
+            "`*"
+        } else {
+            rest = &rest[1..];
+            continue;
+        };
+
+        match rest[2..].find(close) {
+            Some(end) => {
+                matches.push(rest[2..2 + end].to_string());
+                rest = &rest[2 + end + 2..];
+            }
+            None => break,
+        }
+    }
+
+    matches
+}
+
+/// Write the examples of a downloaded puzzle to its example files and fill expected answers into its tests.
+/// Example files that already have content and tests that were edited are left alone.
+pub fn apply(puzzle: PuzzleId) -> io::Result<()> {
+    let markdown = fs::read_to_string(puzzle.data_path("puzzles", "md"))?;
+    let examples = extract(&markdown);
+
+    fs::create_dir_all(format!("data/{}/examples", puzzle.year))?;
+
+    if let Some(input) = &examples.part_1.input {
+        write_example(&puzzle.data_path("examples", "txt"), input)?;
+    }
+
+    let part_2_input = examples.part_2.as_ref().and_then(|e| e.input.as_ref());
+    if let Some(input) = part_2_input {
+        write_example(&puzzle.data_part_path("examples", 2, "txt"), input)?;
+    }
+
+    let module_path = puzzle.bin_path();
+    let Ok(module) = fs::read_to_string(&module_path) else {
+        return Ok(());
+    };
+
+    let mut updated = module.clone();
+
+    if let Some(answer) = &examples.part_1.answer {
+        updated = fill_test(&updated, "test_part_one", answer);
+    }
+
+    if let Some(answer) = examples.part_2.as_ref().and_then(|e| e.answer.as_ref()) {
+        updated = fill_test(&updated, "test_part_two", answer);
+    }
+
+    if updated != module {
+        fs::write(&module_path, updated)?;
+        println!("🎄 Filled expected example answers into \"{module_path}\".");
+    }
+
+    Ok(())
+}
+
+fn write_example(path: &str, input: &str) -> io::Result<()> {
+    if fs::read_to_string(path).is_ok_and(|contents| !contents.trim().is_empty()) {
+        return Ok(());
+    }
+
+    fs::write(path, input)?;
+    println!("🎄 Successfully wrote example to \"{path}\".");
+    Ok(())
+}
+
+/// Replace the empty assertion of a generated test with the expected answer.
+/// Numeric assertions are only filled with numeric answers, string assertions with any answer.
+fn fill_test(module: &str, test_name: &str, answer: &str) -> String {
+    let Some(start) = module.find(&format!("fn {test_name}()")) else {
+        return module.to_string();
+    };
+
+    let end = module[start..]
+        .find("#[test]")
+        .map_or(module.len(), |end| start + end);
+
+    let test = &module[start..end];
+
+    let test = if test.contains(EMPTY_ASSERTION) && answer.parse::<u64>().is_ok() {
+        test.replace(
+            EMPTY_ASSERTION,
+            &format!("assert_eq!(result, Some({answer}));"),
+        )
+    } else if test.contains(EMPTY_STRING_ASSERTION) {
+        test.replace(
+            EMPTY_STRING_ASSERTION,
+            &format!("assert_eq!(result.as_deref(), Some({answer:?}));"),
+        )
+    } else {
+        return module.to_string();
+    };
+
+    format!("{}{test}{}", &module[..start], &module[end..])
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, fill_test, find_emphasized_code, parse_title};
+
+    const PUZZLE: &str = "## --- Day 1: Secret Entrance ---
+
+The dial starts by pointing at `50`.
+
+```
+L1
+```
+
+For example, suppose the attached document contained the following rotations:
+
+```
+L68
+L30
+R48
+```
+
+Because the dial points at `0` a total of three times during this process, the password in this example is `*3*`.
+
+## --- Part Two ---
+
+You remember the dial needs *`0x434C49434B`*.
+
+Following the same rotations as in the above example, the dial points at zero a few extra times, so the new password would be `*6*`.";
+
+    const MODULE: &str = r#"advent_of_code::solution!(1, year = 2025);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_example(PUZZLE, 1));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_example(PUZZLE, 2));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            find_emphasized_code("a `*1*` b *`two`* `c` *d* `*3*`"),
+            vec!["1", "two", "3"]
+        );
+        assert_eq!(find_emphasized_code("`*unterminated"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn extracts_examples() {
+        let examples = extract(PUZZLE);
+        assert_eq!(examples.part_1.input, Some("L68\nL30\nR48\n".into()));
+        assert_eq!(examples.part_1.answer, Some("3".into()));
+
+        let part_2 = examples.part_2.unwrap();
+        assert_eq!(part_2.input, None);
+        assert_eq!(part_2.answer, Some("6".into()));
+    }
+
+    #[test]
+    fn extracts_separate_part_two_examples() {
+        let markdown = PUZZLE.replace(
+            "Following the same rotations",
+            "For example:\n\n```\nR1\nR2\n```\n\nFollowing other rotations",
+        );
+        let part_2 = extract(&markdown).part_2.unwrap();
+        assert_eq!(part_2.input, Some("R1\nR2\n".into()));
+        assert_eq!(part_2.answer, Some("6".into()));
+    }
+
+    #[test]
+    fn handles_missing_part_two() {
+        let markdown = PUZZLE.split("## --- Part Two ---").next().unwrap();
+        assert_eq!(extract(markdown).part_2, None);
+    }
+
+    #[test]
+    fn fills_tests() {
+        let module = fill_test(MODULE, "test_part_two", "6");
+        assert!(module.contains(
+            "part_two(&advent_of_code::template::read_example(PUZZLE, 2));\n        assert_eq!(result, Some(6));"
+        ));
+        assert!(module.contains(
+            "part_one(&advent_of_code::template::read_example(PUZZLE, 1));\n        assert_eq!(result, None);"
+        ));
+
+        let module = fill_test(&module, "test_part_one", "3");
+        assert!(module.contains("assert_eq!(result, Some(3));"));
+        assert_eq!(fill_test(&module, "test_part_one", "4"), module);
+    }
+
+    #[test]
+    fn skips_non_numeric_answers() {
+        assert_eq!(fill_test(MODULE, "test_part_one", "ABC"), MODULE);
+    }
+
+    #[test]
+    fn fills_string_tests() {
+        let module = MODULE.replace(
+            "assert_eq!(result, None);",
+            "assert_eq!(result.as_deref(), None);",
+        );
+        let module = fill_test(&module, "test_part_one", "ABC");
+        assert!(module.contains("assert_eq!(result.as_deref(), Some(\"ABC\"));"));
+    }
+
+    #[test]
+    fn parses_titles() {
+        assert_eq!(
+            parse_title("## --- Day 1: Secret Entrance ---\n\nThe dial...").as_deref(),
+            Some("Day 1: Secret Entrance")
+        );
+        assert_eq!(parse_title("no heading"), None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,420 @@
+/// History of benchmark runs, used to detect performance regressions.
+/// Every `cargo time --store` appends an entry to a JSON-lines file, together with information about the environment it ran in.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+use crate::template::timings::{Timing, Timings};
+
+static HISTORY_FILE_PATH: &str = "./data/timings-history.jsonl";
+
+/// Overrides the machine id that is stored with each entry.
+pub const MACHINE_ID_ENV: &str = "AOC_MACHINE_ID";
+
+/// A single benchmark run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub rustc: Option<String>,
+    pub machine: Option<String>,
+    pub timings: Timings,
+}
+
+/// All benchmark runs, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+/// Comparison of a part against the last stored run. Part `0` is the input parsing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    /// Mean time in nanoseconds of the last stored run.
+    pub before: f64,
+    /// Mean time in nanoseconds of the current run.
+    pub after: f64,
+}
+
+impl HistoryEntry {
+    /// Create an entry for the current environment.
+    pub fn new(timings: Timings) -> Self {
+        HistoryEntry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: get_command_output("git", &["rev-parse", "--short", "HEAD"]),
+            rustc: get_command_output(
+                &env::var("RUSTC").unwrap_or_else(|_| "rustc".into()),
+                &["--version"],
+            ),
+            machine: get_machine_id(),
+            timings,
+        }
+    }
+}
+
+impl History {
+    /// Append an entry to the history file.
+    pub fn append(entry: &HistoryEntry) -> io::Result<()> {
+        let line = JsonValue::from(entry)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_FILE_PATH)?;
+        writeln!(file, "{line}")
+    }
+
+    /// Rehydrate the history from its file. If not present, returns an empty history.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(HISTORY_FILE_PATH) {
+            Ok(contents) => History::try_from(contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// The most recent timing of a puzzle that was stored on the given machine.
+    pub fn last_timing(&self, puzzle: PuzzleId, machine: Option<&str>) -> Option<&Timing> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.machine.as_deref() == machine)
+            .find_map(|entry| entry.timings.data.iter().find(|t| t.puzzle() == puzzle))
+    }
+
+    /// Compare timings against the last stored run of each day on the given machine.
+    pub fn compare(&self, timings: &Timings, machine: Option<&str>) -> Vec<Comparison> {
+        timings
+            .data
+            .iter()
+            .filter_map(|timing| {
+                let baseline = self.last_timing(timing.puzzle(), machine)?;
+                Some(compare_timings(baseline, timing))
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+/// Compare the parts that have stats in both timings.
+fn compare_timings(baseline: &Timing, current: &Timing) -> Vec<Comparison> {
+    [
+        (0, &baseline.parse_stats, &current.parse_stats),
+        (1, &baseline.part_1_stats, &current.part_1_stats),
+        (2, &baseline.part_2_stats, &current.part_2_stats),
+    ]
+    .into_iter()
+    .filter_map(|(part, before, after)| {
+        Some(Comparison {
+            puzzle: current.puzzle(),
+            part,
+            before: before.as_ref()?.mean,
+            after: after.as_ref()?.mean,
+        })
+    })
+    .collect()
+}
+
+impl Comparison {
+    /// Relative change of the mean time in percent, `None` if the baseline is too fast to compare against.
+    pub fn change_percent(&self) -> Option<f64> {
+        if self.before > 0.0 {
+            Some((self.after - self.before) / self.before * 100.0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent()
+            .is_some_and(|change| change > threshold)
+    }
+}
+
+/// Identifies the machine a benchmark ran on. Can be overridden with `AOC_MACHINE_ID`.
+/// The history is meant to be committed, so `/etc/machine-id` is only stored as a hash, see [`hash_machine_id`].
+pub fn get_machine_id() -> Option<String> {
+    env::var(MACHINE_ID_ENV)
+        .ok()
+        .or_else(|| {
+            let machine_id = fs::read_to_string("/etc/machine-id").ok()?;
+            Some(hash_machine_id(machine_id.trim()))
+        })
+        .or_else(|| get_command_output("hostname", &[]))
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+}
+
+/// The machine id is confidential, so it is combined with an application-specific salt and hashed (64-bit FNV-1a).
+/// The hash is stable across Rust versions, unlike the hashers of the standard library.
+fn hash_machine_id(machine_id: &str) -> String {
+    const SALT: &str = "advent-of-code-timings-history:";
+
+    let hash = SALT
+        .bytes()
+        .chain(machine_id.bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+
+    format!("{hash:016x}")
+}
+
+fn get_command_output(cmd: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(cmd).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        for (key, field) in [
+            ("commit", &value.commit),
+            ("rustc", &value.rustc),
+            ("machine", &value.machine),
+        ] {
+            map.insert(
+                key.into(),
+                match field {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        map.insert(
+            "timings".into(),
+            JsonValue::Array(value.timings.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let string = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => v
+                .get::<String>()
+                .cloned()
+                .map(Some)
+                .ok_or(format!("Expected entry.{key} to be null or string.")),
+        };
+
+        let timings = json
+            .get("timings")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected entry.timings to be an array.")?
+            .iter()
+            .map(Timing::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: string("commit")?,
+            rustc: string("rustc")?,
+            machine: string("machine")?,
+            timings: Timings { data: timings },
+        })
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let entries = value
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let json = JsonValue::from_str(line).or(Err("History entry is not valid JSON."))?;
+                HistoryEntry::try_from(&json)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { entries })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Comparison, History, HistoryEntry, hash_machine_id};
+    use crate::template::report::PartStatus;
+    use crate::template::stats::Stats;
+    use crate::template::timings::{Timing, Timings};
+    use crate::template::{Day, PuzzleId};
+    use crate::{day, puzzle, year};
+    use tinyjson::JsonValue;
+
+    fn stats(mean: f64) -> Stats {
+        Stats {
+            mean,
+            median: mean,
+            min: mean,
+            max: mean,
+            p95: mean,
+            stddev: 0.0,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    fn timing(day: u8, part_1: f64, part_2: Option<f64>) -> Timing {
+        Timing {
+            part_1: Some(format!("{part_1}ns")),
+            part_2: part_2.map(|x| format!("{x}ns")),
+            part_1_status: PartStatus::Solved,
+            part_2_status: PartStatus::Solved,
+            part_1_stats: Some(stats(part_1)),
+            part_2_stats: part_2.map(stats),
+            total_nanos: part_1 + part_2.unwrap_or_default(),
+            ..Timing::new(PuzzleId::new(year!(2025), Day::new(day).unwrap()))
+        }
+    }
+
+    fn entry(machine: &str, timings: Vec<Timing>) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: 1_764_565_200,
+            commit: Some("abc1234".into()),
+            rustc: Some("rustc 1.95.0".into()),
+            machine: Some(machine.into()),
+            timings: Timings { data: timings },
+        }
+    }
+
+    fn get_mock_history() -> History {
+        History {
+            entries: vec![
+                entry(
+                    "a",
+                    vec![timing(1, 100.0, Some(200.0)), timing(2, 50.0, None)],
+                ),
+                entry("b", vec![timing(1, 10.0, Some(20.0))]),
+                entry("a", vec![timing(1, 110.0, Some(190.0))]),
+            ],
+        }
+    }
+
+    #[test]
+    fn finds_last_timing_per_machine() {
+        let history = get_mock_history();
+        let last = history.last_timing(puzzle!(2025, 1), Some("a")).unwrap();
+        assert_eq!(last.part_1_stats.as_ref().unwrap().mean, 110.0);
+        let last = history.last_timing(puzzle!(2025, 2), Some("a")).unwrap();
+        assert_eq!(last.day, day!(2));
+        assert!(history.last_timing(puzzle!(2025, 2), Some("b")).is_none());
+        assert!(history.last_timing(puzzle!(2025, 1), Some("c")).is_none());
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let history = get_mock_history();
+        let current = Timings {
+            data: vec![timing(1, 150.0, Some(195.0)), timing(3, 10.0, None)],
+        };
+
+        let comparisons = history.compare(&current, Some("a"));
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].part, 1);
+        assert_eq!(comparisons[0].before, 110.0);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(50.0));
+        assert_eq!(comparisons[1].part, 2);
+        assert!(!comparisons[1].is_regression(5.0));
+    }
+
+    #[test]
+    fn skips_zero_baselines() {
+        let comparison = Comparison {
+            puzzle: puzzle!(2025, 1),
+            part: 1,
+            before: 0.0,
+            after: 10.0,
+        };
+
+        assert_eq!(comparison.change_percent(), None);
+        assert!(!comparison.is_regression(10.0));
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let history = get_mock_history();
+        let contents = history
+            .entries
+            .iter()
+            .map(|entry| JsonValue::from(entry).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let parsed = History::try_from(contents).unwrap();
+        assert_eq!(parsed.entries.len(), 3);
+        assert_eq!(parsed.entries[1].machine, Some("b".into()));
+        assert_eq!(parsed.entries[0].commit, Some("abc1234".into()));
+        assert_eq!(parsed.entries[0].timings.data.len(), 2);
+        assert_eq!(parsed.entries[0].timestamp, 1_764_565_200);
+    }
+
+    #[test]
+    fn hashes_machine_ids() {
+        let machine_id = "b08dfa6083e7567a1921a715000001fb";
+        let hash = hash_machine_id(machine_id);
+
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, hash_machine_id(machine_id));
+        assert_ne!(hash, hash_machine_id("b08dfa6083e7567a1921a715000001fc"));
+        assert!(!hash.contains(machine_id));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_entries() {
+        History::try_from(r#"{ "timestamp": "yesterday", "timings": [] }"#.to_string()).unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,178 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+};
+
+use crate::template::PuzzleId;
+
+/// Where a solution reads its input from, selected with `cargo solve NN --example [k]` or `--input <path>`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input, `data/{year}/inputs/NN.txt`.
+    #[default]
+    Input,
+    /// A numbered example `data/{year}/examples/NN-k.txt`.
+    /// Without a number, each part reads its own example, see [`PuzzleId::example_path`].
+    Example(Option<u8>),
+    /// Any file, `-` reads from stdin.
+    Path(String),
+}
+
+impl InputSource {
+    /// Read the input source from the arguments of the current process.
+    pub fn from_env() -> Result<Self, String> {
+        InputSource::take_from_args(&mut env::args().collect())
+    }
+
+    /// Remove `--example [k]` and `--input <path>` from command-line arguments.
+    /// A number directly following `--example` is read as the number of the example.
+    pub fn take_from_args(args: &mut Vec<String>) -> Result<Self, String> {
+        let mut source = InputSource::Input;
+
+        if let Some(index) = args.iter().position(|arg| arg == "--example") {
+            args.remove(index);
+            let number = args.get(index).and_then(|arg| arg.parse::<u8>().ok());
+            if number.is_some() {
+                args.remove(index);
+            }
+            source = InputSource::Example(number);
+        }
+
+        if let Some(index) = args.iter().position(|arg| arg == "--input") {
+            if source != InputSource::Input {
+                return Err("`--example` and `--input` can not be combined.".into());
+            }
+            args.remove(index);
+            if index >= args.len() {
+                return Err("`--input` expects a path, or `-` for stdin.".into());
+            }
+            source = InputSource::Path(args.remove(index));
+        }
+
+        Ok(source)
+    }
+
+    /// Command-line arguments that select this input, to be passed on to a solution.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Input => vec![],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(number)) => vec!["--example".into(), number.to_string()],
+            InputSource::Path(path) => vec!["--input".into(), path.clone()],
+        }
+    }
+
+    /// Path of the input file of a part, `None` for stdin.
+    pub fn path(&self, puzzle: PuzzleId, part: u8) -> Option<String> {
+        match self {
+            InputSource::Input => Some(puzzle.data_path("inputs", "txt")),
+            InputSource::Example(None) => Some(puzzle.example_path(part)),
+            // NOTE: an explicit example does not fall back to the shared one, a missing file is an error.
+            InputSource::Example(Some(number)) => {
+                Some(puzzle.data_part_path("examples", *number, "txt"))
+            }
+            InputSource::Path(path) if path == "-" => None,
+            InputSource::Path(path) => Some(path.clone()),
+        }
+    }
+
+    /// Read the input of a part.
+    pub fn read(&self, puzzle: PuzzleId, part: u8) -> Result<String, String> {
+        match self.path(puzzle, part) {
+            Some(path) => {
+                fs::read_to_string(&path).map_err(|e| format!("could not read \"{path}\": {e}."))
+            }
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read stdin: {e}."))?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+    use crate::puzzle;
+
+    fn take(args: &[&str]) -> (Result<InputSource, String>, Vec<String>) {
+        let mut args: Vec<String> = args.iter().map(ToString::to_string).collect();
+        let source = InputSource::take_from_args(&mut args);
+        (source, args)
+    }
+
+    #[test]
+    fn takes_input_sources() {
+        assert_eq!(
+            take(&["1", "--release"]),
+            (Ok(InputSource::Input), vec!["1".into(), "--release".into()])
+        );
+        assert_eq!(
+            take(&["1", "--example"]),
+            (Ok(InputSource::Example(None)), vec!["1".into()])
+        );
+        assert_eq!(
+            take(&["1", "--example", "2", "--release"]),
+            (
+                Ok(InputSource::Example(Some(2))),
+                vec!["1".into(), "--release".into()]
+            )
+        );
+        assert_eq!(
+            take(&["1", "--input", "-"]),
+            (Ok(InputSource::Path("-".into())), vec!["1".into()])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_input_sources() {
+        assert!(take(&["1", "--input"]).0.is_err());
+        assert!(take(&["1", "--example", "--input", "a.txt"]).0.is_err());
+    }
+
+    #[test]
+    fn resolves_example_paths() {
+        // Day 1 only has `01.txt`, day 11 has a separate example for part two.
+        assert_eq!(
+            InputSource::Example(None).path(puzzle!(2025, 1), 2),
+            Some("data/2025/examples/01.txt".into())
+        );
+        assert_eq!(
+            InputSource::Example(None).path(puzzle!(2025, 11), 2),
+            Some("data/2025/examples/11-2.txt".into())
+        );
+        assert_eq!(
+            InputSource::Example(Some(2)).path(puzzle!(2025, 11), 1),
+            Some("data/2025/examples/11-2.txt".into())
+        );
+    }
+
+    #[test]
+    fn rejects_missing_examples() {
+        let error = InputSource::Example(Some(7))
+            .read(puzzle!(2025, 1), 1)
+            .unwrap_err();
+        assert!(error.contains("data/2025/examples/01-7.txt"), "{error}");
+    }
+
+    #[test]
+    fn round_trips_input_sources() {
+        for source in [
+            InputSource::Input,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+            InputSource::Path("other.txt".into()),
+        ] {
+            let mut args = source.to_args();
+            assert_eq!(InputSource::take_from_args(&mut args), Ok(source));
+            assert!(args.is_empty());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
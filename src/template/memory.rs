@@ -0,0 +1,240 @@
+/// Heap allocation metrics of solution parts.
+/// `solution!` installs [`CountingAllocator`] as global allocator, which counts allocations while [`track`] is running.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    fmt::Display,
+    hint::black_box,
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+};
+use tinyjson::JsonValue;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Bytes allocated since tracking started. Can become negative if memory allocated before is freed.
+static CURRENT: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+static TOTAL: AtomicU64 = AtomicU64::new(0);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Heap usage of a single run of a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Maximum number of bytes allocated at the same time.
+    pub peak_bytes: u64,
+    /// Sum of the sizes of all allocations.
+    pub total_bytes: u64,
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+}
+
+/// A global allocator that forwards to the system allocator and counts allocations while tracking is enabled.
+pub struct CountingAllocator;
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_allocation(size: usize) {
+    TOTAL.fetch_add(size as u64, Ordering::Relaxed);
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn record_deallocation(size: usize) {
+    CURRENT.fetch_sub(size as i64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        if ENABLED.load(Ordering::Relaxed) {
+            record_deallocation(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Run a function and track its heap allocations.
+/// Returns `None` for the stats if [`CountingAllocator`] is not the global allocator.
+#[allow(clippy::cast_sign_loss)]
+pub fn track<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    reset();
+    ENABLED.store(true, Ordering::SeqCst);
+
+    // NOTE: probe whether the allocator is installed, this allocation is never counted otherwise.
+    drop(black_box(Box::new(0_u8)));
+    let is_installed = COUNT.load(Ordering::SeqCst) > 0;
+    reset();
+
+    let result = func();
+
+    ENABLED.store(false, Ordering::SeqCst);
+
+    let stats = is_installed.then(|| MemoryStats {
+        peak_bytes: PEAK.load(Ordering::SeqCst).max(0) as u64,
+        total_bytes: TOTAL.load(Ordering::SeqCst),
+        allocations: COUNT.load(Ordering::SeqCst),
+    });
+
+    (result, stats)
+}
+
+fn reset() {
+    CURRENT.store(0, Ordering::SeqCst);
+    PEAK.store(0, Ordering::SeqCst);
+    TOTAL.store(0, Ordering::SeqCst);
+    COUNT.store(0, Ordering::SeqCst);
+}
+
+/// Format a number of bytes with binary units, e.g. `1.5MiB`.
+#[allow(clippy::cast_precision_loss)]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1}{unit}")
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak, {} in {} allocations",
+            format_bytes(self.peak_bytes),
+            format_bytes(self.total_bytes),
+            self.allocations
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &MemoryStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+        map.insert(
+            "total_bytes".into(),
+            JsonValue::Number(value.total_bytes as f64),
+        );
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        Ok(MemoryStats {
+            peak_bytes: number("peak_bytes")?,
+            total_bytes: number("total_bytes")?,
+            allocations: number("allocations")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{MemoryStats, format_bytes, track};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(1536), "1.5KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0MiB");
+    }
+
+    #[test]
+    fn formats_stats() {
+        let stats = MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 3,
+        };
+        assert_eq!(stats.to_string(), "2.0KiB peak, 4.0KiB in 3 allocations");
+    }
+
+    #[test]
+    fn roundtrips_stats() {
+        let stats = MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 3,
+        };
+        let json = JsonValue::from(&stats);
+        assert_eq!(MemoryStats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn detects_missing_allocator() {
+        // the test harness uses the system allocator.
+        let (result, stats) = track(|| vec![1, 2, 3].len());
+        assert_eq!(result, 3);
+        assert_eq!(stats, None);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,68 +1,215 @@
-use std::{env, fs};
+use std::{env, fs, time::Duration};
 
-pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod memory;
+pub mod registry;
 pub mod runner;
 
+pub use answer::*;
 pub use day::*;
+pub use input::*;
+pub use puzzle::*;
+pub use year::*;
 
+mod answer;
+mod answers;
+mod calendar;
 mod day;
+mod examples;
+mod history;
+mod input;
+mod puzzle;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
+mod stats;
+mod submissions;
 mod timings;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file to a string. E.g. like `data/2025/inputs/01.txt`.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join(folder)
+        .join(format!("{}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads a numbered example, falling back to the shared one. See [`PuzzleId::example_path`].
+#[must_use]
+pub fn read_example(puzzle: PuzzleId, number: u8) -> String {
+    let cwd = env::current_dir().unwrap();
+    let f = fs::read_to_string(cwd.join(puzzle.example_path(number)));
+    f.expect("could not open example file")
+}
+
+/// Helper function that parses a duration like `500ms`, `2s` or `1.5m`. A bare number is read as seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`."))?;
+
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => {
+            return Err(format!(
+                "invalid duration unit `{unit}`, expecting ms, s or m."
+            ));
+        }
+    };
+
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Parses a size in bytes like `512M` or `2GiB`. Units are binary (`K`, `M` and `G`), a bare number is read as bytes.
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+
+    let value: u64 = value.parse().map_err(|_| format!("invalid size `{s}`."))?;
+
+    let multiplier: u64 = match unit.trim_end_matches("iB").trim_end_matches('B') {
+        "" => 1,
+        "K" | "k" => 1 << 10,
+        "M" | "m" => 1 << 20,
+        "G" | "g" => 1 << 30,
+        _ => return Err(format!("invalid size unit `{unit}`, expecting K, M or G.")),
+    };
+
+    value
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("size `{s}` is too large."))
+}
+
+/// Creates the constants `PUZZLE` and `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The year is passed as `year = 2025`; if omitted, it is read from `AOC_YEAR` at compile time.
+///
+/// With `parse = <function>`, the input is parsed once and each part receives a reference to the
+/// parsed value instead of the raw input. Parsing is then timed separately from the parts.
+///
+/// The generated `main` reads the puzzle input, or the input selected with `--example [k]` or `--input <path>`,
+/// see [`runner::run_main`].
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr, 1 $(, year = $year:expr)? $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $crate::solution!(@year $($year)?), $day, [part_one, 1]; $($parse)?);
+    };
+    ($day:expr, 2 $(, year = $year:expr)? $(, parse = $parse:expr)?) => {
+        $crate::solution!(@impl $crate::solution!(@year $($year)?), $day, [part_two, 2]; $($parse)?);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr $(, year = $year:expr)? $(, parse = $parse:expr)?) => {
+        $crate::solution!(
+            @impl $crate::solution!(@year $($year)?), $day, [part_one, 1] [part_two, 2]; $($parse)?
+        );
+    };
+
+    (@year) => {
+        const {
+            $crate::template::Year::parse(env!("AOC_YEAR"))
+                .expect("invalid `AOC_YEAR`, expecting a value starting with 2015")
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    (@year $year:expr) => {
+        $crate::year!($year)
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*; $($parse:expr)?) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId =
+            $crate::template::PuzzleId::new($year, $crate::day!($day));
+
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        #[allow(dead_code)]
+        const DAY: $crate::template::Day = PUZZLE.day;
+
+        /// Registers the solution with the single `aoc` binary, see [`crate::template::registry`].
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                run: run_solution,
+            };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
+        // NOTE: the single `aoc` binary installs the allocator itself, as every solution is a module of it.
+        #[cfg(not(any(feature = "dhat-heap", feature = "single-binary")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::memory::CountingAllocator =
+            $crate::template::memory::CountingAllocator;
+
+        fn run_solution(input: &str) {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            $( let input = &run_parse($parse, input); )?
+            $( run_part($func, input, PUZZLE, $part); )*
+        }
+
+        #[allow(dead_code)]
+        fn main() {
+            $crate::template::runner::run_main(PUZZLE, run_solution);
         }
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_duration, parse_size};
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+        assert!(parse_duration("fast").is_err());
+        assert!(parse_duration("5h").is_err());
+    }
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("1024"), Ok(1024));
+        assert_eq!(parse_size("512M"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_size("2GiB"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("64kB"), Ok(64 * 1024));
+        assert!(parse_size("M").is_err());
+        assert!(parse_size("5T").is_err());
+    }
+}
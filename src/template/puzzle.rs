@@ -0,0 +1,78 @@
+use std::{fmt::Display, path::Path};
+
+use crate::template::{Day, Year};
+
+/// Identifies a single puzzle of advent by its year and day.
+///
+/// # Display
+/// This value displays as `<year>-<day>`, which is also the name of the puzzle's solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2025-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Name of the binary that holds the solution for this puzzle.
+    pub fn bin_name(self) -> String {
+        self.to_string()
+    }
+
+    /// Path of the source file that holds the solution for this puzzle.
+    pub fn bin_path(self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path of a data file for this puzzle, e.g. `data/2025/inputs/08.txt`.
+    pub fn data_path(self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+
+    /// Path of a data file for a single part of this puzzle, e.g. `data/2025/examples/08-2.txt`.
+    pub fn data_part_path(self, folder: &str, part: u8, extension: &str) -> String {
+        format!(
+            "data/{}/{folder}/{}-{part}.{extension}",
+            self.year, self.day
+        )
+    }
+
+    /// Path of a numbered example, e.g. `data/2025/examples/08-2.txt`.
+    /// Falls back to the shared example `data/2025/examples/08.txt` if there is no numbered one.
+    /// Parts use the example with their number, so `08-2.txt` is the example of part two.
+    pub fn example_path(self, number: u8) -> String {
+        let path = self.data_part_path("examples", number, "txt");
+        if Path::new(&path).exists() {
+            path
+        } else {
+            self.data_path("examples", "txt")
+        }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+    };
+}
+
+/* -------------------------------------------------------------------------- */
@@ -2,7 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::memory::{MemoryStats, format_bytes};
+use crate::template::report::PartStatus;
 use crate::template::timings::Timings;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -25,11 +26,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -55,23 +51,47 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // NOTE: memory columns are only shown if at least one day was timed with `--memory`.
+    let has_memory = timings.data.iter().any(|t| {
+        t.parse_memory.is_some() || t.part_1_memory.is_some() || t.part_2_memory.is_some()
+    });
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+
+    if has_memory {
+        lines.push(
+            "| Day | Parse | Part 1 | Part 2 | Parse Memory | Part 1 Memory | Part 2 Memory |"
+                .into(),
+        );
+        lines.push("| :---: | :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    }
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+        let path = timing.puzzle().bin_path();
+        let mut line = format!(
+            "| [Day {}]({}) | {} | {} | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+            timing
+                .parse
+                .map_or_else(|| "-".into(), |x| format!("`{x}`")),
+            format_part(timing.part_1, timing.part_1_status),
+            format_part(timing.part_2, timing.part_2_status),
+        );
+
+        if has_memory {
+            line.push_str(&format!(
+                " {} | {} | {} |",
+                format_memory(timing.parse_memory.as_ref()),
+                format_memory(timing.part_1_memory.as_ref()),
+                format_memory(timing.part_2_memory.as_ref()),
+            ));
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,6 +101,28 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+fn format_part(duration: Option<String>, status: PartStatus) -> String {
+    match (duration, status) {
+        (_, PartStatus::TimedOut) => "⏱ timed out".into(),
+        (_, PartStatus::OutOfMemory) => "💥 out of memory".into(),
+        (_, PartStatus::Failed) => "❌ failed".into(),
+        (Some(duration), _) => format!("`{duration}`"),
+        (None, _) => "-".into(),
+    }
+}
+
+fn format_memory(memory: Option<&MemoryStats>) -> String {
+    match memory {
+        Some(memory) => format!(
+            "`{}` peak, `{}` total in {} allocs",
+            format_bytes(memory.peak_bytes),
+            format_bytes(memory.total_bytes),
+            memory.allocations
+        ),
+        None => "-".into(),
+    }
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -91,6 +133,14 @@ fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result
 pub fn update(timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    // NOTE: days that are not part of the season (e.g. 13 to 25 in 2025) are left out of the table.
+    let timings = Timings {
+        data: timings
+            .data
+            .into_iter()
+            .filter(|t| t.puzzle().is_in_season())
+            .collect(),
+    };
     let total_millis = timings.total_millis();
     update_content(&mut readme, timings, total_millis)?;
     fs::write(path, &readme)?;
@@ -100,28 +150,37 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        puzzle, template::memory::MemoryStats, template::report::PartStatus,
+        template::timings::Timing, template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3e+10,
+                    ..Timing::new(puzzle!(2025, 1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::TimedOut,
                     total_nanos: 7e+10,
+                    ..Timing::new(puzzle!(2025, 2))
                 },
                 Timing {
-                    day: day!(4),
+                    parse: Some("5ms".into()),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 9e+10,
+                    ..Timing::new(puzzle!(2025, 4))
                 },
             ],
         }
@@ -145,7 +204,7 @@ mod tests {
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
         update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
@@ -167,11 +226,11 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025-01.rs) | - | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | - | `30ms` | ⏱ timed out |",
+            "| [Day 4](./src/bin/2025-04.rs) | `5ms` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +239,44 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_memory_columns() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_memory = Some(MemoryStats {
+            peak_bytes: 1536,
+            total_bytes: 3 * 1024 * 1024,
+            allocations: 12,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains(
+            "| Day | Parse | Part 1 | Part 2 | Parse Memory | Part 1 Memory | Part 2 Memory |"
+        ));
+        assert!(
+            s.contains(
+                "| [Day 1](./src/bin/2025-01.rs) | - | `10ms` | `20ms` | - | `1.5KiB` peak, `3.0MiB` total in 12 allocs | - |"
+            )
+        );
+        assert!(
+            s.contains("| [Day 2](./src/bin/2025-02.rs) | - | `30ms` | ⏱ timed out | - | - | - |")
+        );
+    }
+
+    #[test]
+    fn format_parse_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[2].parse_memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 3,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0).unwrap();
+        assert!(s.contains(
+            "| [Day 4](./src/bin/2025-04.rs) | `5ms` | `40ms` | `50ms` | `2.0KiB` peak, `4.0KiB` total in 3 allocs | - | - |"
+        ));
+    }
 }
@@ -0,0 +1,154 @@
+/// Registry of solutions that are linked into a single `aoc` binary.
+///
+/// Every `src/bin/<year>-<day>.rs` registers itself through the `SOLUTION` constant generated by [`crate::solution`].
+/// With the `single-binary` feature, `build.rs` includes all solution files as modules of the `aoc` binary and
+/// collects their `SOLUTION`s into a static slice. This allows running and benching days in-process, without
+/// invoking cargo for each day. The output matches `cargo all` and `cargo time`.
+use std::{
+    collections::HashSet,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    process,
+};
+
+use crate::template::commands::time::store_timings;
+use crate::template::report;
+use crate::template::run_multi::{DayOutput, RunOptions, SolutionRun, get_puzzles};
+use crate::template::timings::Timings;
+use crate::template::{Day, PuzzleId, Year, all_days, parse_duration};
+
+/// A solution registered by [`crate::solution`].
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Runs all parts of the solution against an input, printing and reporting their results.
+    pub run: fn(&str),
+}
+
+impl Solution {
+    /// Run the solution against its input and collect the reports of all parts.
+    fn run_input(&self) -> SolutionRun {
+        match fs::read_to_string(self.puzzle.data_path("inputs", "txt")) {
+            // NOTE: parts catch their own panics, so only a failed `parse` unwinds. It has been reported already.
+            Ok(input) => SolutionRun {
+                reports: report::collect(|| {
+                    let _ = panic::catch_unwind(AssertUnwindSafe(|| (self.run)(&input)));
+                }),
+                failure: None,
+            },
+            Err(e) => {
+                eprintln!("Could not open input file: {e}");
+                SolutionRun {
+                    reports: vec![],
+                    failure: Some(format!("could not open input file: {e}")),
+                }
+            }
+        }
+    }
+}
+
+/// Entry point of the `aoc` binary.
+///
+/// Usage: `cargo aoc [<day>...] [--year <year>] [--time] [--bench-time <duration>] [--memory] [--store]`
+pub fn main(solutions: &[Solution]) {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            process::exit(1);
+        }
+    };
+
+    let days_to_run = if args.days.is_empty() {
+        all_days(args.year).collect()
+    } else {
+        args.days
+    };
+
+    // NOTE: `--time`, `--bench-time` and `--memory` are read by the runner itself, just like in the solution binaries.
+    let options = RunOptions {
+        is_timed: args.time,
+        is_memory: args.memory,
+        ..RunOptions::default()
+    };
+
+    let timings = run_in_process(args.year, &days_to_run, &options, solutions);
+
+    if let (true, Some(timings)) = (args.store, timings) {
+        store_timings(args.year, &timings);
+    }
+}
+
+/// Run a set of days in-process. Days without a registered solution are reported as not solved.
+fn run_in_process(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+    solutions: &[Solution],
+) -> Option<Timings> {
+    let mut output = DayOutput::default();
+
+    for puzzle in get_puzzles(year, days_to_run) {
+        output.start_day(puzzle);
+
+        let reports = solutions
+            .iter()
+            .find(|solution| solution.puzzle == puzzle)
+            .map(Solution::run_input);
+
+        output.finish_day(puzzle, reports);
+    }
+
+    output.finish(options)
+}
+
+struct Args {
+    year: Year,
+    days: HashSet<Day>,
+    time: bool,
+    memory: bool,
+    store: bool,
+}
+
+fn parse_args() -> Result<Args, Box<dyn std::error::Error>> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let time = args.contains("--time");
+    let memory = args.contains("--memory");
+    let store = args.contains("--store");
+    let _: Option<_> = args.opt_value_from_fn("--bench-time", parse_duration)?;
+
+    let year: Option<Year> = args.opt_value_from_str("--year")?;
+    let year = year
+        .or_else(Year::from_env)
+        .ok_or("no year specified. Pass `--year` or set `AOC_YEAR`.")?;
+
+    let mut days = HashSet::new();
+    while let Some(day) = args.opt_free_from_str::<Day>()? {
+        if !PuzzleId::new(year, day).is_in_season() {
+            return Err(format!(
+                "advent of code {year} only has {} puzzles.",
+                year.puzzle_count()
+            )
+            .into());
+        }
+        days.insert(day);
+    }
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        return Err(format!("unknown argument(s): {remaining:?}.").into());
+    }
+
+    if store && !time {
+        return Err("`--store` can only be used together with `--time`.".into());
+    }
+
+    Ok(Args {
+        year,
+        days,
+        time,
+        memory,
+        store,
+    })
+}
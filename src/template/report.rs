@@ -0,0 +1,271 @@
+/// Machine-readable protocol between solution binaries and `run_multi`.
+/// When `AOC_REPORT_PATH` is set, the `main` generated by `solution!` appends one JSON record per part to that file.
+/// Solutions that run in-process (see [`crate::template::registry`]) report to a collector instead.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    sync::Mutex,
+};
+use tinyjson::JsonValue;
+
+use crate::template::memory::MemoryStats;
+use crate::template::stats::Stats;
+
+pub const REPORT_PATH_ENV: &str = "AOC_REPORT_PATH";
+
+/// Reports written while [`collect`] is running.
+static COLLECTED: Mutex<Option<Vec<PartReport>>> = Mutex::new(None);
+
+/// Outcome of running a single part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part is not implemented yet, e.g. it returned `None`.
+    Unsolved,
+    /// The part returned an error, see [`crate::template::Answer::Error`].
+    Failed,
+    /// The solution was killed because it exceeded the timeout while running the part.
+    TimedOut,
+    /// The solution was killed because it exceeded the memory limit while running the part.
+    OutOfMemory,
+}
+
+impl PartStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::TimedOut => "timed_out",
+            PartStatus::OutOfMemory => "out_of_memory",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            "timed_out" => Ok(PartStatus::TimedOut),
+            "out_of_memory" => Ok(PartStatus::OutOfMemory),
+            _ => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// Result and timing of a single part, as reported by a solution binary.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    pub status: PartStatus,
+    /// The answer of a solved part, or the message of a failed part.
+    pub answer: Option<String>,
+    pub stats: Stats,
+    /// Heap usage of a single run, if measured with `--memory`.
+    pub memory: Option<MemoryStats>,
+}
+
+/// Append a report to the file at `AOC_REPORT_PATH` and to the active collector, if any.
+/// Does nothing if neither is set.
+pub fn write(report: &PartReport) -> io::Result<()> {
+    if let Some(reports) = COLLECTED.lock().unwrap().as_mut() {
+        reports.push(report.clone());
+    }
+
+    let Ok(path) = env::var(REPORT_PATH_ENV) else {
+        return Ok(());
+    };
+
+    let line = JsonValue::from(report)
+        .stringify()
+        .map_err(|e| io::Error::other(e.to_string()))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+/// Run a function in-process and return the reports that were written while it ran.
+pub fn collect(func: impl FnOnce()) -> Vec<PartReport> {
+    *COLLECTED.lock().unwrap() = Some(vec![]);
+    func();
+    COLLECTED.lock().unwrap().take().unwrap_or_default()
+}
+
+/// Read all reports from a JSON-lines file. A missing file yields no reports.
+pub fn read(path: &Path) -> Result<Vec<PartReport>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse(&contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn parse(contents: &str) -> Result<Vec<PartReport>, String> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("Report is not valid JSON."))?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let stats = Stats::try_from(
+            json.get("stats")
+                .ok_or("Expected report.stats to be set.")?,
+        )?;
+
+        let memory = match json.get("memory") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(MemoryStats::try_from(v)?),
+        };
+
+        Ok(PartReport {
+            part,
+            status,
+            answer: answer.cloned(),
+            stats,
+            memory,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartReport, PartStatus, collect, parse, write};
+    use crate::template::memory::MemoryStats;
+    use crate::template::stats::Stats;
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = PartReport {
+            part: 1,
+            status: PartStatus::Solved,
+            answer: Some("Part 2: 10 (74.13ms @ 99999 samples)\n".into()),
+            stats: Stats::from_samples(&[Duration::from_micros(74), Duration::from_micros(75)])
+                .unwrap(),
+            memory: Some(MemoryStats {
+                peak_bytes: 1024,
+                total_bytes: 4096,
+                allocations: 12,
+            }),
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(parse(&line).unwrap(), vec![report]);
+    }
+
+    #[test]
+    fn parses_unsolved_parts() {
+        let stats = r#"{ "mean": 2000, "median": 2000, "min": 1000, "max": 3000, "p95": 2900, "stddev": 10, "samples": 5, "outliers": 0 }"#;
+        let contents = [
+            format!(r#"{{ "part": 1, "status": "solved", "answer": "42", "stats": {stats} }}"#),
+            String::new(),
+            format!(r#"{{ "part": 2, "status": "unsolved", "answer": null, "stats": {stats} }}"#),
+        ]
+        .join("\n");
+
+        let reports = parse(&contents).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].answer, Some("42".into()));
+        assert_eq!(reports[0].stats.samples, 5);
+        assert_eq!(reports[1].status, PartStatus::Unsolved);
+        assert_eq!(reports[1].answer, None);
+        assert_eq!(reports[1].memory, None);
+    }
+
+    #[test]
+    fn collects_reports_in_process() {
+        let report = PartReport {
+            part: 2,
+            status: PartStatus::Unsolved,
+            answer: None,
+            stats: Stats::single(Duration::from_micros(1)),
+            memory: None,
+        };
+
+        let reports = collect(|| write(&report).unwrap());
+        assert_eq!(reports, vec![report]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        parse(r#"{ "part": 1, "status": "maybe" }"#).unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,56 +1,184 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    time::Duration,
+};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::report::{PartReport, PartStatus};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, InputSource, PuzzleId, Year};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Options that apply to every solution run by [`run_multi`].
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub is_release: bool,
+    pub is_timed: bool,
+    /// Time budget for benching a single part. Defaults to 1 second.
+    pub bench_time: Option<Duration>,
+    /// Track heap allocations of each part with the counting allocator installed by [`crate::solution`].
+    pub is_memory: bool,
+    /// Discard the output of solutions. Their reports are still collected.
+    pub is_quiet: bool,
+    /// Number of days that are run concurrently. Below 2, days run sequentially with live output.
+    /// Otherwise, all solutions are built once up front and their output is buffered and printed in day order.
+    pub jobs: usize,
+    /// Solutions that run longer are killed and the running part is reported as timed out.
+    pub timeout: Option<Duration>,
+    /// Solutions that use more memory (resident set size, in bytes) are killed. Only supported on linux.
+    pub memory_limit: Option<u64>,
+    /// Input passed to solutions. Timings are only meaningful for the puzzle input.
+    pub input: InputSource,
+}
+
+impl RunOptions {
+    fn has_limits(&self) -> bool {
+        self.timeout.is_some() || self.memory_limit.is_some()
+    }
+}
+
+pub fn run_multi(year: Year, days_to_run: &HashSet<Day>, options: &RunOptions) -> Option<Timings> {
+    let puzzles = get_puzzles(year, days_to_run);
+    let mut output = DayOutput::default();
+
+    if options.memory_limit.is_some() && !cfg!(target_os = "linux") {
+        eprintln!("Warning: memory limits are only supported on linux and will be ignored.");
+    }
+
+    if options.jobs > 1 {
+        child_commands::run_solutions_parallel(&puzzles, options, |puzzle, run| {
+            output.start_day(puzzle);
+            let run = run.unwrap();
+            let _ = io::stdout().write_all(&run.stdout);
+            let _ = io::stderr().write_all(&run.stderr);
+            output.finish_day(puzzle, run.run);
+        })
+        .unwrap();
+    } else {
+        for puzzle in puzzles {
+            output.start_day(puzzle);
+            output.finish_day(
+                puzzle,
+                child_commands::run_solution(puzzle, options).unwrap(),
+            );
+        }
+    }
 
-    let mut need_space = false;
+    output.finish(options)
+}
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
+/// The puzzles to run for a set of days, as non-duplicate, sorted values.
+pub(crate) fn get_puzzles(year: Year, days_to_run: &HashSet<Day>) -> Vec<PuzzleId> {
+    all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .map(|day| PuzzleId::new(year, day))
+        .collect()
+}
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+/// Reports of a solution run.
+#[derive(Debug, Default)]
+pub struct SolutionRun {
+    pub reports: Vec<PartReport>,
+    /// Why the solution failed as a whole, e.g. because it did not build or exited with an error.
+    pub failure: Option<String>,
+}
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+/// Prints the day headers and summary of a run and collects the timings of each day.
+#[derive(Default)]
+pub(crate) struct DayOutput {
+    timings: Vec<Timing>,
+    /// Days and parts that failed, timed out or ran out of memory, formatted for the summary.
+    failures: Vec<String>,
+    need_space: bool,
+}
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+impl DayOutput {
+    pub fn start_day(&mut self, puzzle: PuzzleId) {
+        if self.need_space {
+            println!();
+        }
+        self.need_space = true;
+
+        println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+        println!("------");
+    }
+
+    /// Collect the reports of a day. `None` signals that the day has not been solved yet.
+    pub fn finish_day(&mut self, puzzle: PuzzleId, run: Option<SolutionRun>) {
+        let Some(SolutionRun { reports, failure }) = run else {
+            println!("Not solved.");
+            return;
+        };
+
+        self.timings.push(Timing::from_reports(puzzle, &reports));
+
+        let failed_parts: Vec<String> = reports
+            .iter()
+            .filter(|report| is_failure(report.status))
+            .map(|report| format_failure(puzzle, report))
+            .collect();
+
+        // a solution that fails before reporting a part, e.g. because its input is missing, fails as a whole.
+        if failed_parts.is_empty() && (failure.is_some() || reports.is_empty()) {
+            let reason = failure.unwrap_or_else(|| "no parts were reported".into());
+            self.failures.push(format!("Day {}: {reason}", puzzle.day));
+        }
+        self.failures.extend(failed_parts);
+    }
+
+    pub fn finish(self, options: &RunOptions) -> Option<Timings> {
+        if !self.failures.is_empty() {
+            println!("\n{ANSI_BOLD}Failures:{ANSI_RESET}");
+            for failure in &self.failures {
+                println!("{failure}");
             }
-        });
+        }
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
+        if options.is_timed {
+            let timings = Timings { data: self.timings };
+            let total_millis = timings.total_millis();
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+            Some(timings)
+        } else {
+            None
+        }
     }
 }
 
+fn is_failure(status: PartStatus) -> bool {
+    matches!(
+        status,
+        PartStatus::Failed | PartStatus::TimedOut | PartStatus::OutOfMemory
+    )
+}
+
+/// Summarize a failed part, e.g. `Day 06, part 1: panicked at src/bin/2025-06.rs:40:18: explicit panic`.
+fn format_failure(puzzle: PuzzleId, report: &PartReport) -> String {
+    let part = match report.part {
+        0 => "parse".to_string(),
+        part => format!("part {part}"),
+    };
+
+    let reason = match (report.status, &report.answer) {
+        (PartStatus::TimedOut, _) => "timed out".to_string(),
+        (PartStatus::OutOfMemory, _) => "out of memory".to_string(),
+        (_, Some(message)) => message.clone(),
+        (_, None) => "failed".to_string(),
+    };
+
+    format!("Day {}, {part}: {reason}", puzzle.day)
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
-    BrokenPipe,
     IO(io::Error),
+    Report(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,199 +187,654 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, RunOptions, SolutionRun};
+    use crate::template::PuzzleId;
+    use crate::template::report::{self, PartReport, PartStatus, REPORT_PATH_ENV};
+    use crate::template::stats::Stats;
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        collections::HashMap,
+        env, fs,
+        io::{self, BufRead, BufReader, Read, Write},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Interval at which running solutions are checked against the limits.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// How a solution process ended.
+    enum Exit {
+        Status(ExitStatus),
+        /// The process was killed after exceeding a limit while running a part.
+        Killed(PartStatus, Duration),
+    }
+
+    /// Output and reports of a solution that was run with buffered output.
+    pub struct BufferedRun {
+        pub stdout: Vec<u8>,
+        pub stderr: Vec<u8>,
+        /// `None` if the day has not been scaffolded yet.
+        pub run: Option<SolutionRun>,
+    }
+
+    /// Run the solution bin for a given day.
+    /// Returns the reports of all parts, or `None` if the day has not been scaffolded yet.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        options: &RunOptions,
+    ) -> Result<Option<SolutionRun>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok(None);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // build up front and run the executable directly, so that neither compiling nor cargo itself count
+        // towards the limits.
+        let Some(executable) = build_solutions(&[puzzle], options)?.remove(&puzzle.bin_name())
+        else {
+            eprintln!("Solution failed to build.");
+            return Ok(Some(SolutionRun {
+                reports: vec![],
+                failure: Some("failed to build".into()),
+            }));
+        };
+
+        let stdout = if options.is_quiet {
+            Stdio::null()
+        } else {
+            Stdio::inherit()
+        };
 
-        if is_release {
-            args.push("--release");
+        // the child reports its results to a file while its output is forwarded to stdout/stderr.
+        let mut cmd = Command::new(executable);
+        cmd.args(get_solution_args(options))
+            .stdout(stdout)
+            .stderr(Stdio::inherit());
+
+        let (exit, mut reports) = with_reports(puzzle, &mut cmd, |cmd| {
+            wait_with_limits(&mut cmd.spawn()?, options)
+        })?;
+
+        let mut failure = None;
+        match exit {
+            Exit::Status(status) if !status.success() => {
+                eprintln!("Solution exited with {status}.");
+                failure = Some(format!("exited with {status}"));
+            }
+            Exit::Status(_) => {}
+            Exit::Killed(status, elapsed) => {
+                report_killed_part(
+                    &mut reports,
+                    has_parse_step(puzzle),
+                    status,
+                    elapsed,
+                    options,
+                    &mut io::stdout(),
+                );
+            }
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        Ok(Some(SolutionRun { reports, failure }))
+    }
+
+    /// Build all solution bins once, then run up to `options.jobs` of them concurrently.
+    /// The output of each day is buffered and `on_done` is called for the days in the order of `puzzles`.
+    pub fn run_solutions_parallel(
+        puzzles: &[PuzzleId],
+        options: &RunOptions,
+        mut on_done: impl FnMut(PuzzleId, Result<BufferedRun, Error>),
+    ) -> Result<(), Error> {
+        let scaffolded: Vec<PuzzleId> = puzzles
+            .iter()
+            .copied()
+            .filter(|puzzle| Path::new(&puzzle.bin_path()).exists())
+            .collect();
+
+        let executables = build_solutions(&scaffolded, options)?;
+
+        let next = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..options.jobs.min(puzzles.len()) {
+                let sender = sender.clone();
+                let (next, executables) = (&next, &executables);
+
+                scope.spawn(move || {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&puzzle) = puzzles.get(index) else {
+                            break;
+                        };
+
+                        let run =
+                            run_buffered(puzzle, executables.get(&puzzle.bin_name()), options);
+                        if sender.send((index, run)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+
+            // NOTE: drop the original sender so that the receiver finishes once all workers are done.
+            drop(sender);
+
+            // results arrive in any order, so buffer them until all previous days have been printed.
+            let mut pending = HashMap::new();
+            let mut current = 0;
+
+            for (index, run) in receiver {
+                pending.insert(index, run);
+
+                while let Some(run) = pending.remove(&current) {
+                    on_done(puzzles[current], run);
+                    current += 1;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn run_buffered(
+        puzzle: PuzzleId,
+        executable: Option<&PathBuf>,
+        options: &RunOptions,
+    ) -> Result<BufferedRun, Error> {
+        if !Path::new(&puzzle.bin_path()).exists() {
+            return Ok(BufferedRun {
+                stdout: vec![],
+                stderr: vec![],
+                run: None,
+            });
+        }
+
+        let Some(executable) = executable else {
+            return Ok(BufferedRun {
+                stdout: vec![],
+                stderr: "Solution failed to build.\n".into(),
+                run: Some(SolutionRun {
+                    reports: vec![],
+                    failure: Some("failed to build".into()),
+                }),
+            });
+        };
+
+        let mut cmd = Command::new(executable);
+        cmd.args(get_solution_args(options))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let ((exit, mut stdout, mut stderr), mut reports) =
+            with_reports(puzzle, &mut cmd, |cmd| {
+                let mut child = cmd.spawn()?;
+                let stdout = read_in_background(child.stdout.take());
+                let stderr = read_in_background(child.stderr.take());
+                let exit = wait_with_limits(&mut child, options)?;
+                Ok((exit, stdout.join().unwrap(), stderr.join().unwrap()))
+            })?;
+
+        let mut failure = None;
+        match exit {
+            Exit::Status(status) if !status.success() => {
+                let _ = writeln!(stderr, "Solution exited with {status}.");
+                failure = Some(format!("exited with {status}"));
+            }
+            Exit::Status(_) => {}
+            Exit::Killed(status, elapsed) => {
+                report_killed_part(
+                    &mut reports,
+                    has_parse_step(puzzle),
+                    status,
+                    elapsed,
+                    options,
+                    &mut stdout,
+                );
+            }
+        }
+
+        Ok(BufferedRun {
+            stdout: if options.is_quiet { vec![] } else { stdout },
+            stderr,
+            run: Some(SolutionRun { reports, failure }),
+        })
+    }
+
+    fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buffer = vec![];
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut buffer);
+            }
+            buffer
+        })
+    }
+
+    /// Wait for a solution to exit. Kills the solution if it exceeds the limits set in `options`.
+    fn wait_with_limits(child: &mut Child, options: &RunOptions) -> io::Result<Exit> {
+        if !options.has_limits() {
+            return child.wait().map(Exit::Status);
+        }
+
+        let start = Instant::now();
+
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Exit::Status(status));
+            }
+
+            let exceeded = if options.timeout.is_some_and(|t| start.elapsed() >= t) {
+                Some(PartStatus::TimedOut)
+            } else if options
+                .memory_limit
+                .is_some_and(|limit| get_memory_usage(child.id()).is_some_and(|x| x > limit))
+            {
+                Some(PartStatus::OutOfMemory)
+            } else {
+                None
+            };
+
+            if let Some(status) = exceeded {
+                // NOTE: the child may have exited in the meantime, in which case `kill` fails.
+                let _ = child.kill();
+                child.wait()?;
+                return Ok(Exit::Killed(status, start.elapsed()));
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Returns `true` if the solution of a puzzle parses its input once, i.e. passes `parse = ...` to [`crate::solution`].
+    fn has_parse_step(puzzle: PuzzleId) -> bool {
+        fs::read_to_string(puzzle.bin_path()).is_ok_and(|source| {
+            source
+                .split_once("solution!(")
+                .and_then(|(_, rest)| rest.split_once(");"))
+                .is_some_and(|(args, _)| args.contains("parse ="))
+        })
+    }
+
+    /// Report the part that was running when a solution was killed, i.e. the part after the last reported one.
+    /// Solutions with a parse step were parsing if nothing was reported yet.
+    fn report_killed_part(
+        reports: &mut Vec<PartReport>,
+        has_parse_step: bool,
+        status: PartStatus,
+        elapsed: Duration,
+        options: &RunOptions,
+        out: &mut impl Write,
+    ) {
+        let part = match reports.iter().map(|r| r.part + 1).max() {
+            Some(part) => part,
+            None if has_parse_step => 0,
+            None => 1,
+        };
+        if part > 2 {
+            return;
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        let reason = match status {
+            PartStatus::OutOfMemory => format!(
+                "exceeded the memory limit of {}MiB",
+                options.memory_limit.unwrap_or_default() / 1024 / 1024
+            ),
+            _ => format!("timed out after {:.1?}", options.timeout.unwrap_or(elapsed)),
+        };
 
-        let mut cmd = Command::new("cargo")
+        // NOTE: the solution might have been killed while printing an intermediate result.
+        let _ = write!(out, "\r");
+        let label = match part {
+            0 => "Parse".to_string(),
+            part => format!("Part {part}"),
+        };
+        let _ = writeln!(out, "{label}: ✖ {reason}");
+
+        reports.push(PartReport {
+            part,
+            status,
+            answer: None,
+            stats: Stats::single(elapsed),
+            memory: None,
+        });
+    }
+
+    /// Resident set size of a process in bytes.
+    #[cfg(target_os = "linux")]
+    fn get_memory_usage(pid: u32) -> Option<u64> {
+        parse_vm_rss(&fs::read_to_string(format!("/proc/{pid}/status")).ok()?)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn get_memory_usage(_pid: u32) -> Option<u64> {
+        None
+    }
+
+    /// Parse the `VmRSS` entry of `/proc/<pid>/status`, which is given in kB.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    fn parse_vm_rss(status: &str) -> Option<u64> {
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kilobytes * 1024)
+    }
+
+    /// Build the bins of the given days in a single cargo invocation.
+    /// Returns the paths of the executables that were built, keyed by bin name.
+    fn build_solutions(
+        puzzles: &[PuzzleId],
+        options: &RunOptions,
+    ) -> Result<HashMap<String, PathBuf>, Error> {
+        if puzzles.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut args = vec![
+            "build".to_string(),
+            "--quiet".to_string(),
+            // keep building the other days if one of them fails to compile.
+            "--keep-going".to_string(),
+            "--message-format=json-render-diagnostics".to_string(),
+        ];
+
+        if options.is_release {
+            args.push("--release".into());
+        }
+
+        for puzzle in puzzles {
+            args.push("--bin".into());
+            args.push(puzzle.bin_name());
+        }
+
+        let mut child = Command::new("cargo")
             .args(&args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .stderr(Stdio::inherit())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let mut executables = HashMap::new();
+
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines() {
+                if let Some((name, path)) = parse_artifact(&line?) {
+                    executables.insert(name, path);
+                }
+            }
+        }
 
-        let mut output = vec![];
+        child.wait()?;
+        Ok(executables)
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    /// Parse a line of cargo's JSON output. Returns the target name and executable path of built binaries.
+    fn parse_artifact(line: &str) -> Option<(String, PathBuf)> {
+        let json = JsonValue::from_str(line).ok()?;
+        let message = json.get::<HashMap<String, JsonValue>>()?;
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if message.get("reason")?.get::<String>()? != "compiler-artifact" {
+            return None;
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        let executable = message.get("executable")?.get::<String>()?;
+
+        let name = message
+            .get("target")?
+            .get::<HashMap<String, JsonValue>>()?
+            .get("name")?
+            .get::<String>()?;
 
-        Ok(output)
+        Some((name.clone(), PathBuf::from(executable)))
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Arguments passed to the solution binary itself.
+    fn get_solution_args(options: &RunOptions) -> Vec<String> {
+        let mut args = vec![];
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        if options.is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            if let Some(bench_time) = options.bench_time {
+                args.push("--bench-time".into());
+                args.push(format!("{}ms", bench_time.as_millis()));
+            }
+        }
 
-                timings.total_nanos += nanos;
-            });
+        if options.is_memory {
+            args.push("--memory".into());
+        }
 
-        timings
+        args.extend(options.input.to_args());
+        args
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Run a solution command with a report file and collect the reports it wrote.
+    fn with_reports<T>(
+        puzzle: PuzzleId,
+        cmd: &mut Command,
+        run: impl FnOnce(&mut Command) -> std::io::Result<T>,
+    ) -> Result<(T, Vec<PartReport>), Error> {
+        let report_path = get_report_path(puzzle);
+        remove_report(&report_path)?;
+
+        let result = run(cmd.env(REPORT_PATH_ENV, &report_path))?;
+
+        let reports = report::read(&report_path).map_err(Error::Report)?;
+        remove_report(&report_path)?;
+
+        Ok((result, reports))
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn get_report_path(puzzle: PuzzleId) -> PathBuf {
+        env::temp_dir().join(format!("aoc-report-{}-{puzzle}.jsonl", process::id()))
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    fn remove_report(path: &Path) -> Result<(), Error> {
+        match fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
     }
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use super::{has_parse_step, parse_artifact, parse_vm_rss, report_killed_part};
+        use crate::puzzle;
+        use crate::template::report::{PartReport, PartStatus};
+        use crate::template::run_multi::RunOptions;
+        use crate::template::stats::Stats;
+        use std::{path::PathBuf, time::Duration};
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_bin_artifacts() {
+            let line = r#"{"reason":"compiler-artifact","target":{"kind":["bin"],"name":"2025-01"},"executable":"/repo/target/release/2025-01","fresh":true}"#;
+            assert_eq!(
+                parse_artifact(line),
+                Some((
+                    "2025-01".into(),
+                    PathBuf::from("/repo/target/release/2025-01")
+                ))
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn ignores_other_messages() {
+            let lib = r#"{"reason":"compiler-artifact","target":{"kind":["lib"],"name":"advent_of_code"},"executable":null}"#;
+            assert_eq!(parse_artifact(lib), None);
+            assert_eq!(
+                parse_artifact(r#"{"reason":"build-finished","success":true}"#),
+                None
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(parse_artifact("not json"), None);
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+        fn parses_memory_usage() {
+            let status = "Name:\tsolution\nVmPeak:\t  20000 kB\nVmRSS:\t    1024 kB\n";
+            assert_eq!(parse_vm_rss(status), Some(1024 * 1024));
+            assert_eq!(parse_vm_rss("Name:\tsolution\n"), None);
+        }
+
+        #[test]
+        fn reports_killed_parts() {
+            let options = RunOptions {
+                timeout: Some(Duration::from_secs(5)),
+                ..RunOptions::default()
+            };
+
+            let mut reports = vec![PartReport {
+                part: 1,
+                status: PartStatus::Solved,
+                answer: Some("42".into()),
+                stats: Stats::single(Duration::from_secs(1)),
+                memory: None,
+            }];
+            let mut out = vec![];
+
+            report_killed_part(
+                &mut reports,
+                false,
+                PartStatus::TimedOut,
+                Duration::from_secs(5),
+                &options,
+                &mut out,
+            );
+
+            assert_eq!(reports.len(), 2);
+            assert_eq!(reports[1].part, 2);
+            assert_eq!(reports[1].status, PartStatus::TimedOut);
+            assert_eq!(
+                String::from_utf8(out).unwrap(),
+                "\rPart 2: ✖ timed out after 5.0s\n"
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn reports_kills_before_the_first_report() {
+            let options = RunOptions {
+                memory_limit: Some(64 * 1024 * 1024),
+                ..RunOptions::default()
+            };
+
+            for (has_parse_step, part, output) in [
+                (true, 0, "\rParse: ✖ exceeded the memory limit of 64MiB\n"),
+                (false, 1, "\rPart 1: ✖ exceeded the memory limit of 64MiB\n"),
+            ] {
+                let mut reports = vec![];
+                let mut out = vec![];
+
+                report_killed_part(
+                    &mut reports,
+                    has_parse_step,
+                    PartStatus::OutOfMemory,
+                    Duration::from_secs(1),
+                    &options,
+                    &mut out,
+                );
+
+                assert_eq!(reports.len(), 1);
+                assert_eq!(reports[0].part, part);
+                assert_eq!(reports[0].status, PartStatus::OutOfMemory);
+                assert_eq!(String::from_utf8(out).unwrap(), output);
+            }
+        }
+
+        #[test]
+        fn detects_parse_steps() {
+            assert!(has_parse_step(puzzle!(2025, 8)));
+            assert!(!has_parse_step(puzzle!(2025, 1)));
+            assert!(!has_parse_step(puzzle!(2025, 25)));
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{DayOutput, SolutionRun, format_failure};
+    use crate::puzzle;
+    use crate::template::report::{PartReport, PartStatus};
+    use crate::template::stats::Stats;
+
+    fn report(part: u8, status: PartStatus, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status,
+            answer: answer.map(Into::into),
+            stats: Stats::single(Duration::from_secs(1)),
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn formats_failures() {
+        let message = "panicked at src/bin/2025-06.rs:40:18: explicit panic";
+        assert_eq!(
+            format_failure(
+                puzzle!(2025, 6),
+                &report(1, PartStatus::Failed, Some(message))
+            ),
+            format!("Day 06, part 1: {message}")
+        );
+        assert_eq!(
+            format_failure(puzzle!(2025, 6), &report(0, PartStatus::Failed, None)),
+            "Day 06, parse: failed"
+        );
+        assert_eq!(
+            format_failure(puzzle!(2025, 8), &report(2, PartStatus::TimedOut, None)),
+            "Day 08, part 2: timed out"
+        );
+    }
+    #[test]
+    fn collects_day_failures() {
+        let mut output = DayOutput::default();
+
+        // exited before reporting a part, e.g. because the input is missing.
+        output.finish_day(
+            puzzle!(2025, 7),
+            Some(SolutionRun {
+                reports: vec![],
+                failure: Some("exited with exit status: 1".into()),
+            }),
+        );
+        // exited successfully without reporting a part.
+        output.finish_day(puzzle!(2025, 9), Some(SolutionRun::default()));
+        // the failed part explains the exit status.
+        output.finish_day(
+            puzzle!(2025, 10),
+            Some(SolutionRun {
+                reports: vec![report(0, PartStatus::Failed, Some("bad input"))],
+                failure: Some("exited with exit status: 101".into()),
+            }),
+        );
+        // not scaffolded yet.
+        output.finish_day(puzzle!(2025, 11), None);
+        output.finish_day(
+            puzzle!(2025, 12),
+            Some(SolutionRun {
+                reports: vec![report(1, PartStatus::Solved, Some("1"))],
+                failure: None,
+            }),
+        );
+
+        assert_eq!(
+            output.failures,
+            vec![
+                "Day 07: exited with exit status: 1",
+                "Day 09: no parts were reported",
+                "Day 10, parse: bad input",
+            ]
+        );
     }
 }
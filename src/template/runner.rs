@@ -1,142 +1,318 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, SubmissionOutcome};
+use crate::template::memory::{self, MemoryStats};
+use crate::template::report::{self, PartReport, PartStatus};
+use crate::template::stats::Stats;
+use crate::template::submissions::{self, SubmissionLog};
+use crate::template::{
+    ANSI_ITALIC, ANSI_RESET, Answer, InputSource, IntoAnswer, PuzzleId, ocr, parse_duration,
+};
+
+/// Default time spent sampling a part when benching.
+const DEFAULT_BENCH_BUDGET: Duration = Duration::from_secs(1);
+/// Bounds for the number of samples taken when benching.
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 10_000;
+
+/// The only part that [`run_part`] runs, `0` runs all parts.
+static PART_FILTER: AtomicU8 = AtomicU8::new(0);
+
+/// Message and location of the panic caught last by [`catch_panic`].
+static PANIC_MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Entry point of the solution binaries generated by [`crate::solution`].
+/// Runs the solution against the input selected by the command-line arguments, see [`InputSource`].
+/// If the parts have different examples, each part is run separately against its own example.
+pub fn run_main(puzzle: PuzzleId, run: fn(&str)) {
+    let source = InputSource::from_env().unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    });
+
+    let read = |part: u8| {
+        source.read(puzzle, part).unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+    };
+
+    if source.path(puzzle, 1) == source.path(puzzle, 2) {
+        run(&read(1));
+        return;
+    }
+
+    for part in 1..=2 {
+        PART_FILTER.store(part, Ordering::Relaxed);
+        run(&read(part));
+    }
+
+    PART_FILTER.store(0, Ordering::Relaxed);
+}
+
+pub fn run_part<I: Copy, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let filter = PART_FILTER.load(Ordering::Relaxed);
+    if filter != 0 && filter != part {
+        return;
+    }
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (answer, stats, memory) = run_timed(func, input, |result| {
+        let answer = match result {
+            Ok(result) => result.into_answer(),
+            Err(message) => Answer::Error(message),
+        };
+        print_result(&answer, &part_str, "");
+        answer
+    });
+
+    print_result(
+        &answer,
+        &part_str,
+        &format_measurements(&stats, memory.as_ref()),
+    );
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let part_report = PartReport {
+        part,
+        status: answer.status(),
+        answer: answer.text(),
+        stats,
+        memory,
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Err(e) = report::write(&part_report) {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    submit_result(&answer, puzzle, part);
+}
+
+/// Run the `parse` function of a solution that shares its parsed input between parts.
+/// Parsing is timed separately and reported as part `0`.
+///
+/// The parts can not run without a parsed input, so a panic while parsing is reported and then resumed.
+pub fn run_parse<'a, T>(func: impl Fn(&'a str) -> T, input: &'a str) -> T {
+    let (parsed, stats, memory) = run_timed(func, input, |parsed| {
+        if parsed.is_ok() {
+            print!("Parse: ✔");
+        }
+        parsed
+    });
+
+    print!("\r");
+    match &parsed {
+        Ok(_) => println!("Parse: ✔{}", format_measurements(&stats, memory.as_ref())),
+        Err(message) => println!("Parse: ✖ {ANSI_ITALIC}{message}{ANSI_RESET}"),
+    }
+
+    let part_report = PartReport {
+        part: 0,
+        status: if parsed.is_ok() {
+            PartStatus::Solved
+        } else {
+            PartStatus::Failed
+        },
+        answer: parsed.as_ref().err().cloned(),
+        stats,
+        memory,
+    };
+
+    if let Err(e) = report::write(&part_report) {
+        eprintln!("Failed to write report: {e}");
+    }
+
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(message) => panic::resume_unwind(Box::new(message)),
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
+///     The time budget can be changed with `--bench-time <duration>`.
+///
+/// With `--memory`, heap allocations of the first execution are tracked.
+/// The result of the first execution is passed to `hook` before benching, which is not timed.
+/// If the first execution panics, `hook` receives the panic message and the function is not benched.
+fn run_timed<I: Copy, T, U>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl FnOnce(Result<T, String>) -> U,
+) -> (U, Stats, Option<MemoryStats>) {
+    let is_memory = env::args().any(|x| x == "--memory");
+
     let timer = Instant::now();
-    let result = {
+    let (result, memory) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        if is_memory {
+            memory::track(|| catch_panic(|| func(input)))
+        } else {
+            (catch_panic(|| func(input)), None)
+        }
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let is_ok = result.is_ok();
+    let result = hook(result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if is_ok && std::env::args().any(|x| x == "--time") {
+        bench(func, input, get_bench_budget())
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    (result, stats, memory)
+}
+
+/// Run a function and catch a panic, returning its message and location instead.
+/// The default panic hook is replaced while the function runs, so the panic is not printed.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    let previous_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(|info| {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".into());
+
+        let message = match info.location() {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        };
+
+        *PANIC_MESSAGE.lock().unwrap() = Some(message);
+    }));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    panic::set_hook(previous_hook);
+
+    result.map_err(|_| {
+        PANIC_MESSAGE
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| "panicked".into())
+    })
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Bench a function: warm up for a tenth of the budget, then sample until the budget is spent.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, budget: Duration) -> Stats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let warmup = Instant::now();
+    while warmup.elapsed() < budget / 10 {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let start = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < MIN_SAMPLES || (start.elapsed() < budget && timers.len() < MAX_SAMPLES) {
         let timer = Instant::now();
         black_box(func(black_box(input)));
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: at least `MIN_SAMPLES` samples were taken.
+    Stats::from_samples(&timers).unwrap()
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Parse the `--bench-time` argument passed to the solution, if any.
+fn get_bench_budget() -> Duration {
+    let args: Vec<String> = env::args().collect();
+
+    let Some(index) = args.iter().position(|x| x == "--bench-time") else {
+        return DEFAULT_BENCH_BUDGET;
+    };
+
+    match args.get(index + 1).map(|x| parse_duration(x)) {
+        Some(Ok(budget)) => budget,
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --bench-time 500ms");
+            process::exit(1);
+        }
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_measurements(stats: &Stats, memory: Option<&MemoryStats>) -> String {
+    match memory {
+        Some(memory) => format!(" ({stats}; {memory})"),
+        None => format!(" ({stats})"),
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(answer: &Answer, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
-            } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
-            }
-        }
-        None => {
+    let summary = match answer {
+        Answer::Value(value) => format!("{ANSI_BOLD}{value}{ANSI_RESET}"),
+        Answer::Multiline(_) => "▼".into(),
+        Answer::Error(message) => format!("✖ {ANSI_ITALIC}{message}{ANSI_RESET}"),
+        Answer::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
                 print!("\r");
                 println!("{part}: ✖             ");
             }
+            return;
+        }
+    };
+
+    let str = format!("{part}: {summary}{duration_str}");
+
+    if is_intermediate_result {
+        print!("{str}");
+        return;
+    }
+
+    print!("\r");
+    println!("{str}");
+
+    if let Answer::Multiline(art) = answer {
+        println!("{}", art.trim_end());
+        if let Some(letters) = ocr(art) {
+            println!("{part} reads: {ANSI_BOLD}{letters}{ANSI_RESET}");
         }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured, see [`crate::template::aoc_client`].
+///  3. the submission log does not rule the answer out, see [`crate::template::submissions`].
+///  4. the answer was computed from the puzzle input, not an example or custom input.
+///  5. the part returned an answer that can be submitted, see [`Answer::submission`].
+///
+/// Accepted answers are recorded in `data/answers.json`.
+fn submit_result(answer: &Answer, puzzle: PuzzleId, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +328,67 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    if InputSource::take_from_args(&mut args.clone()) != Ok(InputSource::Input) {
+        eprintln!("Not submitting: the answer was not computed from the puzzle input.");
+        return;
+    }
+
+    let result = match (answer.submission(), answer) {
+        (Some(result), _) => result,
+        (None, Answer::Error(message)) => {
+            eprintln!("Not submitting: the part failed with `{message}`.");
+            return;
+        }
+        (None, Answer::Multiline(_)) => {
+            eprintln!("Not submitting: the letters of the answer could not be read.");
+            return;
+        }
+        (None, _) => {
+            eprintln!("Not submitting: the part has no answer.");
+            return;
+        }
+    };
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let mut log = match SubmissionLog::read_from_file(puzzle) {
+        Ok(log) => log,
+        Err(e) => {
+            eprintln!("Failed to read submission log: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = log.check(part, &result, submissions::now()) {
+        eprintln!("Not submitting: {refusal}");
+        return;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    match client.submit(puzzle, part, &result) {
+        Ok(submission) => {
+            println!("{}", submission.message);
+            log.record(part, &result, &submission, submissions::now());
+            if let Err(e) = log.store_file(puzzle) {
+                eprintln!("Failed to store submission log: {e}");
+            }
+
+            if submission.outcome == SubmissionOutcome::Correct {
+                match Answers::record(puzzle, part, &result) {
+                    Ok(()) => println!("Recorded answer in the answers file."),
+                    Err(e) => eprintln!("Failed to record answer: {e}"),
+                }
+            }
+        }
+        Err(e) => eprintln!("Failed to submit answer: {e}"),
+    }
 }
@@ -0,0 +1,225 @@
+/// Summary statistics over the samples of a benchmark run.
+use std::{collections::HashMap, fmt::Display, time::Duration};
+use tinyjson::JsonValue;
+
+/// Statistics of a benchmark run. All durations are in nanoseconds.
+///
+/// Samples outside of the Tukey fences (1.5 times the interquartile range) are rejected as outliers
+/// before the statistics are computed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p95: f64,
+    pub stddev: f64,
+    pub samples: u128,
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Statistics for a single, unbenched run.
+    pub fn single(duration: Duration) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = duration.as_nanos() as f64;
+
+        Stats {
+            mean: nanos,
+            median: nanos,
+            min: nanos,
+            max: nanos,
+            p95: nanos,
+            stddev: 0.0,
+            samples: 1,
+            outliers: 0,
+        }
+    }
+
+    /// Compute statistics for a set of samples. Returns `None` if there are no samples.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = 1.5 * (q3 - q1);
+
+        let kept: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1 - fence && *x <= q3 + fence)
+            .collect();
+
+        let count = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / count;
+        let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Some(Stats {
+            mean,
+            median: percentile(&kept, 50.0),
+            min: kept[0],
+            max: kept[kept.len() - 1],
+            p95: percentile(&kept, 95.0),
+            stddev: variance.sqrt(),
+            samples: kept.len() as u128,
+            outliers: (sorted.len() - kept.len()) as u128,
+        })
+    }
+
+    pub fn mean_duration(&self) -> Duration {
+        nanos_to_duration(self.mean)
+    }
+}
+
+/// Linearly interpolated percentile of a sorted, non-empty slice.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos as u64)
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.samples == 1 && self.outliers == 0 {
+            return write!(f, "{:.1?}", self.mean_duration());
+        }
+
+        write!(
+            f,
+            "{:.1?} ± {:.1?} @ {} samples; median {:.1?}, p95 {:.1?}, range {:.1?}..{:.1?}",
+            self.mean_duration(),
+            nanos_to_duration(self.stddev),
+            self.samples,
+            nanos_to_duration(self.median),
+            nanos_to_duration(self.p95),
+            nanos_to_duration(self.min),
+            nanos_to_duration(self.max),
+        )?;
+
+        if self.outliers > 0 {
+            write!(f, ", {} outliers", self.outliers)?;
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(Stats {
+            mean: number("mean")?,
+            median: number("median")?,
+            min: number("min")?,
+            max: number("max")?,
+            p95: number("p95")?,
+            stddev: number("stddev")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.mean, 30.0);
+        assert_eq!(stats.median, 30.0);
+        assert_eq!(stats.min, 10.0);
+        assert_eq!(stats.max, 50.0);
+        assert_eq!(stats.p95, 48.0);
+        assert_eq!(stats.stddev, 200_f64.sqrt());
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.max, 12.0);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn formats_single_runs() {
+        let stats = Stats::single(Duration::from_micros(74));
+        assert_eq!(stats.to_string(), "74.0µs");
+    }
+
+    #[test]
+    fn formats_benchmarks() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(
+            stats.to_string(),
+            "30.0ns ± 14.0ns @ 5 samples; median 30.0ns, p95 48.0ns, range 10.0ns..50.0ns"
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
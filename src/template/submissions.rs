@@ -0,0 +1,494 @@
+/// Log of all answers submitted for a day, together with the verdict of the server.
+/// The log is used to refuse submissions that are known to be wrong and to enforce the cooldown between attempts.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, Error},
+    path::Path,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+use crate::template::aoc_client::{Hint, Submission, SubmissionOutcome};
+
+/// Verdict of the server for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The part was already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+/// A single submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubmissionRecord {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// No further answers are submitted before this time, in seconds since the unix epoch.
+    pub cooldown_until: Option<u64>,
+}
+
+/// All answers submitted for a single day, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionLog {
+    pub data: Vec<SubmissionRecord>,
+}
+
+/// Reason for not submitting an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    KnownWrong { verdict: Verdict },
+    TooHigh { bound: String },
+    TooLow { bound: String },
+    Cooldown { seconds: u64 },
+}
+
+impl Verdict {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    /// Returns `true` if the server rejected the answer itself.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "rate_limited" => Ok(Verdict::RateLimited),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+impl From<SubmissionOutcome> for Verdict {
+    fn from(value: SubmissionOutcome) -> Self {
+        match value {
+            SubmissionOutcome::Correct => Verdict::Correct,
+            SubmissionOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => Verdict::TooHigh,
+            SubmissionOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => Verdict::TooLow,
+            SubmissionOutcome::Incorrect { hint: None } => Verdict::Wrong,
+            SubmissionOutcome::RateLimited { .. } => Verdict::RateLimited,
+            SubmissionOutcome::WrongLevel => Verdict::WrongLevel,
+            SubmissionOutcome::Unknown => Verdict::Unknown,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::KnownWrong { verdict } => {
+                write!(f, "this answer was already submitted and is {verdict}.")
+            }
+            Refusal::TooHigh { bound } => {
+                write!(
+                    f,
+                    "this answer is too high, `{bound}` was already too high."
+                )
+            }
+            Refusal::TooLow { bound } => {
+                write!(f, "this answer is too low, `{bound}` was already too low.")
+            }
+            Refusal::Cooldown { seconds } => {
+                write!(
+                    f,
+                    "please wait {seconds}s before submitting another answer."
+                )
+            }
+        }
+    }
+}
+
+impl SubmissionLog {
+    /// Rehydrate the log of a day from its file. If not present, returns an empty log.
+    pub fn read_from_file(puzzle: PuzzleId) -> Result<Self, String> {
+        match fs::read_to_string(get_log_path(puzzle)) {
+            Ok(contents) => SubmissionLog::try_from(contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionLog::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Dehydrate the log of a day to its file.
+    pub fn store_file(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = get_log_path(puzzle);
+        if let Some(dir) = Path::new(&path).parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Check whether an answer may be submitted at `now` (in seconds since the unix epoch).
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(seconds) = self
+            .data
+            .iter()
+            .filter_map(|record| record.cooldown_until)
+            .max()
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+        {
+            return Err(Refusal::Cooldown { seconds });
+        }
+
+        let records = || self.data.iter().filter(|record| record.part == part);
+
+        if let Some(record) = records().find(|record| record.verdict == Verdict::Correct) {
+            return Err(Refusal::AlreadySolved {
+                answer: record.answer.clone(),
+            });
+        }
+
+        if let Some(record) = records().find(|r| r.answer == answer && r.verdict.is_wrong()) {
+            return Err(Refusal::KnownWrong {
+                verdict: record.verdict,
+            });
+        }
+
+        // NOTE: bounds can only be checked for numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            records()
+                .filter(move |record| record.verdict == verdict)
+                .filter_map(|record| Some((record.answer.parse::<i128>().ok()?, record)))
+        };
+
+        if let Some((_, record)) = bound(Verdict::TooHigh)
+            .filter(|(bound, _)| value >= *bound)
+            .min_by_key(|(bound, _)| *bound)
+        {
+            return Err(Refusal::TooHigh {
+                bound: record.answer.clone(),
+            });
+        }
+
+        if let Some((_, record)) = bound(Verdict::TooLow)
+            .filter(|(bound, _)| value <= *bound)
+            .max_by_key(|(bound, _)| *bound)
+        {
+            return Err(Refusal::TooLow {
+                bound: record.answer.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Record the response of the server for a submitted answer.
+    pub fn record(&mut self, part: u8, answer: &str, submission: &Submission, now: u64) {
+        self.data.push(SubmissionRecord {
+            part,
+            answer: answer.into(),
+            verdict: submission.outcome.into(),
+            timestamp: now,
+            cooldown_until: submission.cooldown().map(|wait| now + wait.as_secs()),
+        });
+    }
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn get_log_path(puzzle: PuzzleId) -> String {
+    puzzle.data_path("submissions", "json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmissionLog> for JsonValue {
+    fn from(value: &SubmissionLog) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for SubmissionLog {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SubmissionLog {
+            data: json_data
+                .iter()
+                .map(SubmissionRecord::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmissionRecord> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &SubmissionRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.as_str().into()),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "cooldown_until".into(),
+            match value.cooldown_until {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmissionRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .parse()?;
+
+        let cooldown_until = match json.get("cooldown_until") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(_) => Some(number("cooldown_until")? as u64),
+        };
+
+        Ok(SubmissionRecord {
+            part: number("part")? as u8,
+            answer: answer.clone(),
+            verdict,
+            timestamp: number("timestamp")? as u64,
+            cooldown_until,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Refusal, SubmissionLog, Verdict};
+    use crate::template::aoc_client::{Hint, Submission, SubmissionOutcome};
+    use tinyjson::JsonValue;
+
+    fn submission(outcome: SubmissionOutcome, message: &str) -> Submission {
+        Submission {
+            outcome,
+            message: message.into(),
+        }
+    }
+
+    fn wrong(hint: Option<Hint>) -> Submission {
+        submission(
+            SubmissionOutcome::Incorrect { hint },
+            "That's not the right answer. Please wait one minute before trying again.",
+        )
+    }
+
+    fn get_mock_log() -> SubmissionLog {
+        let mut log = SubmissionLog::default();
+        log.record(1, "100", &wrong(Some(Hint::TooHigh)), 1000);
+        log.record(1, "10", &wrong(Some(Hint::TooLow)), 1100);
+        log.record(1, "50", &wrong(Some(Hint::TooHigh)), 1200);
+        log.record(1, "abc", &wrong(None), 1300);
+        log
+    }
+
+    #[test]
+    fn records_submissions() {
+        let log = get_mock_log();
+        assert_eq!(log.data.len(), 4);
+        assert_eq!(log.data[0].verdict, Verdict::TooHigh);
+        assert_eq!(log.data[3].verdict, Verdict::Wrong);
+        assert_eq!(log.data[3].cooldown_until, Some(1360));
+    }
+
+    #[test]
+    fn enforces_cooldown() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(2, "1", 1330),
+            Err(Refusal::Cooldown { seconds: 30 })
+        );
+        assert_eq!(log.check(2, "1", 1360), Ok(()));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(1, "abc", 2000),
+            Err(Refusal::KnownWrong {
+                verdict: Verdict::Wrong
+            })
+        );
+        assert_eq!(log.check(2, "abc", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let log = get_mock_log();
+        assert_eq!(
+            log.check(1, "75", 2000),
+            Err(Refusal::TooHigh { bound: "50".into() })
+        );
+        assert_eq!(
+            log.check(1, "-3", 2000),
+            Err(Refusal::TooLow { bound: "10".into() })
+        );
+        assert_eq!(log.check(1, "11", 2000), Ok(()));
+        assert_eq!(log.check(1, "49", 2000), Ok(()));
+        assert_eq!(log.check(1, "xyz", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut log = get_mock_log();
+        log.record(
+            1,
+            "42",
+            &submission(SubmissionOutcome::Correct, "That's the right answer!"),
+            2000,
+        );
+        assert_eq!(
+            log.check(1, "43", 2000),
+            Err(Refusal::AlreadySolved {
+                answer: "42".into()
+            })
+        );
+    }
+
+    #[test]
+    fn allows_resubmitting_rate_limited_answers() {
+        let mut log = SubmissionLog::default();
+        log.record(
+            1,
+            "42",
+            &submission(
+                SubmissionOutcome::RateLimited { wait: None },
+                "You gave an answer too recently.",
+            ),
+            1000,
+        );
+        assert_eq!(log.check(1, "42", 1000), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_log() {
+        let log = get_mock_log();
+        let json = JsonValue::from(&log).stringify().unwrap();
+        let parsed = SubmissionLog::try_from(json).unwrap();
+        assert_eq!(parsed.data, log.data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_log() {
+        SubmissionLog::try_from(r#"{ "data": [{ "part": 1, "verdict": "maybe" }] }"#.to_string())
+            .unwrap();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
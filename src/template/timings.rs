@@ -1,16 +1,37 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::memory::MemoryStats;
+use crate::template::report::{PartReport, PartStatus};
+use crate::template::stats::Stats;
+use crate::template::{Day, PuzzleId, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
+    /// Only set for solutions that parse their input once, see [`crate::solution`].
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    /// Distinguishes parts that were not solved from parts that hit a resource limit.
+    pub part_1_status: PartStatus,
+    pub part_2_status: PartStatus,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    pub parse_stats: Option<Stats>,
+    /// Heap usage, only set when timed with `--memory`.
+    pub part_1_memory: Option<MemoryStats>,
+    pub part_2_memory: Option<MemoryStats>,
+    pub parse_memory: Option<MemoryStats>,
     pub total_nanos: f64,
 }
 
@@ -30,11 +51,12 @@ impl Timings {
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(TIMINGS_FILE_PATH) {
+            Ok(contents) => Timings::try_from(contents),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(e.to_string()),
+        }
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
@@ -46,24 +68,101 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle() == timing.puzzle()) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(Timing::puzzle);
         Timings { data }
     }
 
+    /// Only keep timings that belong to the given year.
+    pub fn for_year(&self, year: Year) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|t| t.year == year)
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle() == puzzle && t.part_1.is_some() && t.part_2.is_some())
+    }
+}
+
+impl Timing {
+    /// Timing of a day without any solved parts.
+    pub fn new(puzzle: PuzzleId) -> Self {
+        Timing {
+            year: puzzle.year,
+            day: puzzle.day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            part_1_status: PartStatus::Unsolved,
+            part_2_status: PartStatus::Unsolved,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_stats: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            parse_memory: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Collect the timings of a day from the reports of its solution binary.
+    pub fn from_reports(puzzle: PuzzleId, reports: &[PartReport]) -> Self {
+        let mut timing = Timing::new(puzzle);
+
+        for report in reports {
+            match report.part {
+                1 => timing.part_1_status = report.status,
+                2 => timing.part_2_status = report.status,
+                _ => {}
+            }
+        }
+
+        for report in reports.iter().filter(|r| r.status == PartStatus::Solved) {
+            let duration = format!("{:.1?}", report.stats.mean_duration());
+            let stats = Some(report.stats.clone());
+            let memory = report.memory.clone();
+
+            match report.part {
+                0 => {
+                    (timing.parse, timing.parse_stats, timing.parse_memory) =
+                        (Some(duration), stats, memory);
+                }
+                1 => {
+                    (timing.part_1, timing.part_1_stats, timing.part_1_memory) =
+                        (Some(duration), stats, memory);
+                }
+                2 => {
+                    (timing.part_2, timing.part_2_stats, timing.part_2_memory) =
+                        (Some(duration), stats, memory);
+                }
+                _ => continue,
+            }
+
+            timing.total_nanos += report.stats.mean;
+        }
+
+        timing
+    }
+
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new(self.year, self.day)
     }
 }
 
@@ -111,6 +210,7 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
@@ -133,6 +233,43 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            match value.parse.clone() {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        for (key, status) in [
+            ("part_1_status", value.part_1_status),
+            ("part_2_status", value.part_2_status),
+        ] {
+            map.insert(key.into(), JsonValue::String(status.as_str().into()));
+        }
+
+        for (key, stats) in [
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+            ("parse_stats", &value.parse_stats),
+        ] {
+            map.insert(
+                key.into(),
+                stats.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
+        for (key, memory) in [
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+            ("parse_memory", &value.parse_memory),
+        ] {
+            map.insert(
+                key.into(),
+                memory.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -145,12 +282,32 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        // NOTE: timings stored before multiple years were supported belong to the configured year.
+        let year = match json.get("year") {
+            None => Year::from_env()
+                .ok_or("Expected `AOC_YEAR` to be set for timings stored without a year.")?,
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+        };
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // NOTE: parse timings are optional to support timings stored before they were recorded.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+
         let part_1 = json
             .get("part_1")
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
@@ -161,15 +318,63 @@ impl TryFrom<&JsonValue> for Timing {
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
             .ok_or("Expected timing.part_2 to be null or string.")?;
 
+        // NOTE: statuses are optional to support timings stored before they were recorded.
+        let status = |key: &str, duration: Option<&String>| match json.get(key) {
+            None => Ok(if duration.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            }),
+            Some(v) => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be a string."))?
+                .parse(),
+        };
+
+        let part_1_status = status("part_1_status", part_1)?;
+        let part_2_status = status("part_2_status", part_2)?;
+
+        // NOTE: stats are optional to support timings stored before they were recorded.
+        let stats = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => Stats::try_from(v).map(Some),
+        };
+
+        let part_1_stats = stats("part_1_stats")?;
+        let part_2_stats = stats("part_2_stats")?;
+        let parse_stats = stats("parse_stats")?;
+
+        // NOTE: memory is only recorded when timing with `--memory`.
+        let memory = |key: &str| match json.get(key) {
+            None => Ok(None),
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => MemoryStats::try_from(v).map(Some),
+        };
+
+        let part_1_memory = memory("part_1_memory")?;
+        let part_2_memory = memory("part_2_memory")?;
+        let parse_memory = memory("parse_memory")?;
+
         let total_nanos = json
             .get("total_nanos")
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
         Ok(Timing {
+            year,
             day,
+            parse: parse.cloned(),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_status,
+            part_2_status,
+            part_1_stats,
+            part_2_stats,
+            parse_stats,
+            part_1_memory,
+            part_2_memory,
+            parse_memory,
             total_nanos,
         })
     }
@@ -179,7 +384,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{puzzle, template::report::PartStatus};
 
     use super::{Timing, Timings};
 
@@ -187,42 +392,91 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3e+10,
+                    ..Timing::new(puzzle!(2025, 1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 7e+10,
+                    ..Timing::new(puzzle!(2025, 2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
+                    part_1_status: PartStatus::Solved,
                     total_nanos: 4e+10,
+                    ..Timing::new(puzzle!(2025, 4))
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{report::PartStatus, timings::Timings},
+            year,
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
+            assert_eq!(timing.year, year!(2025));
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "part_1_stats": { "mean": 1000000, "median": 900000, "min": 800000, "max": 1500000, "p95": 1400000, "stddev": 1000, "samples": 90, "outliers": 10 }, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            let stats = timing.part_1_stats.as_ref().unwrap();
+            assert_eq!(stats.median, 900_000_f64);
+            assert_eq!(stats.outliers, 10);
+            assert_eq!(timing.part_2_stats, None);
+        }
+
+        #[test]
+        fn handles_json_statuses() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "part_2_status": "timed_out", "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_status, PartStatus::Solved);
+            assert_eq!(timing.part_2_status, PartStatus::TimedOut);
+        }
+
+        #[test]
+        fn handles_json_memory() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "part_1_memory": { "peak_bytes": 2048, "total_bytes": 4096, "allocations": 3 }, "part_2_memory": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_memory.as_ref().unwrap().peak_bytes, 2048);
+            assert_eq!(timing.part_1_memory.as_ref().unwrap().allocations, 3);
+            assert_eq!(timing.part_2_memory, None);
+            assert_eq!(timing.parse_memory, None);
+        }
+
+        #[test]
+        fn handles_json_without_year() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.year, year!(2025));
+            assert_eq!(timing.day, day!(1));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -253,7 +507,7 @@ mod tests {
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -270,7 +524,8 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
+            puzzle,
+            template::report::PartStatus,
             template::timings::{Timing, Timings},
         };
 
@@ -278,49 +533,147 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(puzzle!(2025, 1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(timings.is_day_complete(puzzle!(2025, 1)));
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
+                    part_1_status: PartStatus::Solved,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(puzzle!(2025, 1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(puzzle!(2025, 1)));
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(puzzle!(2025, 1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(puzzle!(2025, 1)));
+        }
+    }
+
+    mod from_reports {
+        use std::time::Duration;
+
+        use crate::{
+            puzzle,
+            template::memory::MemoryStats,
+            template::report::{PartReport, PartStatus},
+            template::stats::Stats,
+            template::timings::Timing,
+        };
+
+        fn report(part: u8, status: PartStatus, nanos: u64) -> PartReport {
+            PartReport {
+                part,
+                status,
+                answer: Some("10".into()),
+                stats: Stats::single(Duration::from_nanos(nanos)),
+                memory: None,
+            }
+        }
+
+        #[test]
+        fn collects_solved_parts() {
+            let timing = Timing::from_reports(
+                puzzle!(2025, 1),
+                &[
+                    report(1, PartStatus::Solved, 74),
+                    report(2, PartStatus::Solved, 74_130_000),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 74_130_074_f64);
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.part_1.unwrap(), "74.0ns");
+            assert_eq!(timing.part_2.unwrap(), "74.1ms");
+            assert_eq!(timing.part_2_stats.unwrap().samples, 1);
+        }
+
+        #[test]
+        fn collects_parse_times() {
+            let timing = Timing::from_reports(
+                puzzle!(2025, 1),
+                &[
+                    report(0, PartStatus::Solved, 1_000),
+                    report(1, PartStatus::Solved, 2_000),
+                    report(2, PartStatus::Solved, 3_000),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 6_000_f64);
+            assert_eq!(timing.parse.unwrap(), "1.0µs");
+            assert_eq!(timing.parse_stats.unwrap().mean, 1_000_f64);
+        }
+
+        #[test]
+        fn collects_memory() {
+            let memory = MemoryStats {
+                peak_bytes: 1024,
+                total_bytes: 2048,
+                allocations: 2,
+            };
+            let timing = Timing::from_reports(
+                puzzle!(2025, 1),
+                &[
+                    PartReport {
+                        memory: Some(memory.clone()),
+                        ..report(1, PartStatus::Solved, 1_000)
+                    },
+                    report(2, PartStatus::Solved, 2_000),
+                ],
+            );
+            assert_eq!(timing.part_1_memory, Some(memory));
+            assert_eq!(timing.part_2_memory, None);
+            assert_eq!(timing.parse_memory, None);
+        }
+
+        #[test]
+        fn handles_missing_parts() {
+            let timing =
+                Timing::from_reports(puzzle!(2025, 1), &[report(1, PartStatus::Unsolved, 1000)]);
+            assert_eq!(timing.total_nanos, 0_f64);
+            assert!(timing.part_1.is_none());
+            assert!(timing.part_2.is_none());
+            assert_eq!(timing.part_1_status, PartStatus::Unsolved);
+        }
+
+        #[test]
+        fn collects_timed_out_parts() {
+            let timing = Timing::from_reports(
+                puzzle!(2025, 1),
+                &[
+                    report(1, PartStatus::Solved, 1000),
+                    report(2, PartStatus::TimedOut, 5000),
+                ],
+            );
+            assert_eq!(timing.total_nanos, 1000_f64);
+            assert_eq!(timing.part_1_status, PartStatus::Solved);
+            assert_eq!(timing.part_2_status, PartStatus::TimedOut);
+            assert_eq!(timing.part_2, None);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            day, puzzle,
             template::timings::{Timing, Timings},
+            year,
         };
 
         use super::get_mock_timings;
@@ -329,12 +682,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(puzzle!(2025, 3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,12 +697,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(puzzle!(2025, 2))],
             };
             let merged = timings.merge(&other);
 
@@ -365,6 +708,22 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn handles_timings_of_other_years() {
+            let timings = get_mock_timings();
+
+            let other = Timings {
+                data: vec![Timing::new(puzzle!(2024, 2))],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].year, year!(2024));
+            assert_eq!(merged.data[0].day, day!(2));
+            assert_eq!(merged.data[2].total_nanos, 7e+10);
+            assert_eq!(merged.for_year(year!(2025)).data.len(), 3);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
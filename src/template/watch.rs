@@ -0,0 +1,269 @@
+/// Reruns a solution whenever its source or data files change, see `cargo solve NN --watch`.
+/// Files are polled for changes to their modification time, which avoids a dependency on platform file watchers.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::report::PartReport;
+use crate::template::run_multi::{RunOptions, child_commands};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, InputSource, PuzzleId};
+
+/// Interval at which the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors often write a file in several steps, so wait for changes to settle before rerunning.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Modification times of the watched files. Files that do not exist are absent.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub fn watch(puzzle: PuzzleId, is_release: bool, input: InputSource) {
+    let options = RunOptions {
+        is_release,
+        input,
+        ..RunOptions::default()
+    };
+
+    let mut previous: Option<Vec<PartReport>> = None;
+    let mut snapshot = take_snapshot(puzzle);
+
+    loop {
+        run_tests(puzzle);
+
+        let reports = match child_commands::run_solution(puzzle, &options) {
+            Ok(Some(run)) => Some(run.reports),
+            Ok(None) => {
+                eprintln!(
+                    "Solution \"{}\" does not exist, scaffold it first.",
+                    puzzle.bin_path()
+                );
+                None
+            }
+            Err(e) => {
+                eprintln!("Failed to run solution: {e:?}");
+                None
+            }
+        };
+
+        if let Some(reports) = reports {
+            if let Some(previous) = &previous {
+                println!("---");
+                for line in diff_answers(previous, &reports) {
+                    println!("{line}");
+                }
+            }
+            previous = Some(reports);
+        }
+
+        println!(
+            "{ANSI_ITALIC}Watching {} for changes...{ANSI_RESET}",
+            puzzle.bin_name()
+        );
+        snapshot = wait_for_change(puzzle, snapshot);
+        println!();
+    }
+}
+
+/// Files that trigger a rerun: the solution, the library and the data files of the day, e.g. `data/2025/examples/08-2.txt`.
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(puzzle.bin_path())];
+    collect_library_files(Path::new("src"), &mut files);
+
+    let day = puzzle.day.to_string();
+    let folders = fs::read_dir(format!("data/{}", puzzle.year))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()));
+
+    for folder in folders {
+        let data_files = fs::read_dir(folder)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                let name = path.file_name().and_then(|name| name.to_str());
+                name.is_some_and(|name| name.starts_with(&day) && name.ends_with(".txt"))
+            });
+        files.extend(data_files);
+    }
+
+    files
+}
+
+/// Source files of the library, i.e. all of `src` except for the other days in `src/bin`.
+fn collect_library_files(folder: &Path, files: &mut Vec<PathBuf>) {
+    let paths = fs::read_dir(folder)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()));
+
+    for path in paths {
+        if path.is_dir() {
+            if path != Path::new("src/bin") {
+                collect_library_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn take_snapshot(puzzle: PuzzleId) -> Snapshot {
+    watched_files(puzzle)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+/// Block until a watched file changes, returning the new snapshot.
+fn wait_for_change(puzzle: PuzzleId, snapshot: Snapshot) -> Snapshot {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        if take_snapshot(puzzle) != snapshot {
+            thread::sleep(DEBOUNCE);
+            return take_snapshot(puzzle);
+        }
+    }
+}
+
+/// Run the tests of the solution, which check the examples.
+fn run_tests(puzzle: PuzzleId) {
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &puzzle.bin_name()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    match status {
+        Ok(status) if status.success() => {}
+        Ok(_) => eprintln!("{ANSI_ITALIC}Example tests failed.{ANSI_RESET}"),
+        Err(e) => eprintln!("Failed to run tests: {e}"),
+    }
+}
+
+/// Compare the answers of a run with the previous run, one line per part. Parsing (part 0) has no answer to compare.
+fn diff_answers(previous: &[PartReport], current: &[PartReport]) -> Vec<String> {
+    let answer = |reports: &[PartReport], part: u8| {
+        reports
+            .iter()
+            .find(|report| report.part == part)
+            .and_then(|report| report.answer.clone())
+    };
+
+    let mut parts: Vec<u8> = previous
+        .iter()
+        .chain(current)
+        .map(|r| r.part)
+        .filter(|&part| part > 0)
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let before = answer(previous, part);
+            let after = answer(current, part);
+            let show = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "✖".into());
+
+            if before == after {
+                format!("Part {part}: {} (unchanged)", show(&after))
+            } else {
+                format!(
+                    "Part {part}: {} → {ANSI_BOLD}{}{ANSI_RESET}",
+                    show(&before),
+                    show(&after)
+                )
+            }
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::{diff_answers, watched_files};
+    use crate::puzzle;
+    use crate::template::report::{PartReport, PartStatus};
+    use crate::template::stats::Stats;
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status: if answer.is_some() {
+                PartStatus::Solved
+            } else {
+                PartStatus::Unsolved
+            },
+            answer: answer.map(Into::into),
+            stats: Stats::single(Duration::from_micros(1)),
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = vec![report(1, Some("42")), report(2, None)];
+        let current = vec![report(1, Some("42")), report(2, Some("7"))];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec![
+                "Part 1: 42 (unchanged)".to_string(),
+                format!("Part 2: ✖ → {ANSI_BOLD}7{ANSI_RESET}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_parse_reports() {
+        let previous = vec![report(0, None), report(1, Some("42"))];
+        let current = vec![report(0, None), report(1, Some("42"))];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec!["Part 1: 42 (unchanged)".to_string()]
+        );
+    }
+
+    #[test]
+    fn watches_library_files() {
+        let files = watched_files(puzzle!(2025, 5));
+
+        assert!(files.contains(&PathBuf::from("./src/bin/2025-05.rs")));
+        assert!(files.contains(&PathBuf::from("src/lib.rs")));
+        assert!(files.contains(&PathBuf::from("src/grid.rs")));
+        assert!(files.contains(&PathBuf::from("src/template/runner.rs")));
+        assert!(!files.contains(&PathBuf::from("src/bin/2025-06.rs")));
+        assert!(files.contains(&PathBuf::from("data/2025/examples/05.txt")));
+    }
+
+    #[test]
+    fn diffs_missing_parts() {
+        let previous = vec![report(1, Some("42"))];
+        let current = vec![report(1, Some("43")), report(2, Some("7"))];
+
+        assert_eq!(
+            diff_answers(&previous, &current),
+            vec![
+                format!("Part 1: 42 → {ANSI_BOLD}43{ANSI_RESET}"),
+                format!("Part 2: ✖ → {ANSI_BOLD}7{ANSI_RESET}"),
+            ]
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
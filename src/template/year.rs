@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// A valid year of advent (i.e. an integer from 2015 onwards).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Parses a [`Year`] in a const context. Used by [`crate::solution`] to read `AOC_YEAR` at compile time.
+    pub const fn parse(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() || bytes.len() > 4 {
+            return None;
+        }
+
+        let mut year: u16 = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+
+    /// Reads the [`Year`] from the `AOC_YEAR` environment variable, if set and valid.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub const fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent, starting with 2015")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value starting with 2015")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_in_const_context() {
+        const YEAR: Option<Year> = Year::parse("2025");
+        assert_eq!(YEAR, Some(Year(2025)));
+    }
+
+    #[test]
+    fn rejects_invalid_years() {
+        assert_eq!(Year::parse(""), None);
+        assert_eq!(Year::parse("2014"), None);
+        assert_eq!(Year::parse("20x5"), None);
+        assert_eq!(Year::parse("20255"), None);
+        assert!("1999".parse::<Year>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,157 @@
+//! Disjoint sets, for puzzles that merge elements into groups, e.g. circuits or regions.
+//!
+//! ```
+//! use advent_of_code::union_find::UnionFind;
+//!
+//! let mut sets = UnionFind::new(4);
+//! assert!(sets.union(0, 1));
+//! assert!(!sets.union(1, 0));
+//! assert_eq!(sets.size_of(1), 2);
+//! assert_eq!(sets.set_count(), 3);
+//! ```
+
+/// Disjoint sets of the elements `0..len`, merged with union by size and path halving.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` sets with one element each.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            set_count: len,
+        }
+    }
+
+    /// Number of elements in all sets.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// The representative of the set that contains `element`.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            let grandparent = self.parents[self.parents[element]];
+            self.parents[element] = grandparent;
+            element = grandparent;
+        }
+        element
+    }
+
+    /// Merges the sets of `a` and `b`, `false` if they already were the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] >= self.sizes[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.set_count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set that contains `element`.
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Sizes of all sets, largest first.
+    pub fn set_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&element| self.parents[element] == element)
+            .map(|root| self.sizes[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Minimum spanning forest of the elements `0..len` with Kruskal's algorithm.
+/// Returns the edges `(a, b, weight)` that join two sets, in the order they were added,
+/// i.e. by weight with ties broken by the elements.
+pub fn kruskal<W: Ord + Copy>(
+    len: usize,
+    edges: impl IntoIterator<Item = (usize, usize, W)>,
+) -> Vec<(usize, usize, W)> {
+    let mut edges: Vec<(usize, usize, W)> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|&(a, b, weight)| (weight, a, b));
+
+    let mut sets = UnionFind::new(len);
+    let mut forest = Vec::with_capacity(len.saturating_sub(1));
+
+    for (a, b, weight) in edges {
+        if sets.union(a, b) {
+            forest.push((a, b, weight));
+            if sets.set_count() == 1 {
+                break;
+            }
+        }
+    }
+
+    forest
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{UnionFind, kruskal};
+
+    #[test]
+    fn merges_sets() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.size_of(2), 4);
+        assert_eq!(sets.set_count(), 3);
+        assert_eq!(sets.set_sizes(), vec![4, 1, 1]);
+    }
+
+    #[test]
+    fn builds_spanning_forests() {
+        let edges = [(0, 1, 4), (1, 2, 1), (0, 2, 2), (2, 3, 7), (4, 5, 3)];
+        assert_eq!(
+            kruskal(6, edges),
+            vec![(1, 2, 1), (0, 2, 2), (4, 5, 3), (2, 3, 7)]
+        );
+        assert_eq!(kruskal(3, [(0, 1, 1), (1, 2, 1), (0, 2, 1)]).len(), 2);
+        assert!(kruskal::<u8>(0, []).is_empty());
+    }
+}
+
+/* -------------------------------------------------------------------------- */